@@ -0,0 +1,13 @@
+// Deterministic noise, so that runs can be compared against each other
+pub fn binary(len: usize) -> Vec<u8> {
+    let mut state: u32 = 0x9e37_79b9;
+
+    (0..len)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state as u8
+        })
+        .collect()
+}
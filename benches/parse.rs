@@ -0,0 +1,40 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use dataurl::DataUrl;
+
+mod common;
+
+use common::binary;
+
+fn parse(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parse");
+    let inputs: Vec<(&str, String)> = vec![
+        ("small text", "data:,Hello%2C%20World%21".to_string()),
+        (
+            "small text with query",
+            "data:text/plain;charset=UTF-8,a%20b?c=d#e".to_string(),
+        ),
+        ("large binary, base64", {
+            let mut data_url = DataUrl::from_bytes(&binary(1 << 20));
+            data_url.set_media_type(Some("application/octet-stream".to_string()));
+            data_url.set_is_base64_encoded(true);
+            data_url.to_string()
+        }),
+        ("large binary, percent", {
+            let mut data_url = DataUrl::from_bytes(&binary(1 << 20));
+            data_url.set_media_type(Some("application/octet-stream".to_string()));
+            data_url.to_string()
+        }),
+    ];
+
+    for (name, input) in &inputs {
+        group.throughput(Throughput::Bytes(input.len() as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(name), input, |b, input| {
+            b.iter(|| DataUrl::parse(input).unwrap())
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, parse);
+criterion_main!(benches);
@@ -0,0 +1,34 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use dataurl::DataUrl;
+
+mod common;
+
+use common::binary;
+
+fn to_string(c: &mut Criterion) {
+    let mut group = c.benchmark_group("to_string");
+    let small_text: DataUrl = DataUrl::from_text("Hello, World!");
+    let mut large_binary_base64: DataUrl = DataUrl::from_bytes(&binary(1 << 20));
+    large_binary_base64.set_media_type(Some("application/octet-stream".to_string()));
+    large_binary_base64.set_is_base64_encoded(true);
+    let mut large_binary_percent: DataUrl = large_binary_base64.clone();
+    large_binary_percent.set_is_base64_encoded(false);
+
+    for (name, data_url) in [
+        ("small text", &small_text),
+        ("large binary, base64", &large_binary_base64),
+        ("large binary, percent", &large_binary_percent),
+    ] {
+        group.throughput(Throughput::Bytes(data_url.data().len() as u64));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(name),
+            data_url,
+            |b, data_url| b.iter(|| data_url.to_string()),
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, to_string);
+criterion_main!(benches);
@@ -0,0 +1,18 @@
+fn main() {
+    // The C header is only generated when building with the C API enabled, into OUT_DIR like any build script output;
+    // include/dataurl.h is its committed copy, refreshed with `make header`
+    #[cfg(feature = "capi")]
+    {
+        let crate_dir: String = std::env::var("CARGO_MANIFEST_DIR").unwrap();
+        let out_dir: String = std::env::var("OUT_DIR").unwrap();
+        let header: String = format!("{}/dataurl.h", out_dir);
+
+        println!("cargo:rerun-if-changed=src/capi.rs");
+        println!("cargo:rerun-if-changed=cbindgen.toml");
+        println!("cargo:rustc-env=DATAURL_GENERATED_HEADER={}", header);
+
+        cbindgen::generate(&crate_dir)
+            .expect("Unable to generate C bindings")
+            .write_to_file(&header);
+    }
+}
@@ -0,0 +1,19 @@
+#![no_main]
+
+use dataurl::DataUrl;
+use libfuzzer_sys::fuzz_target;
+
+// Whatever parse() accepts has to come back unchanged from parse(to_string())
+fuzz_target!(|input: &str| {
+    if let Ok(data_url) = DataUrl::parse(input) {
+        let reparsed: DataUrl = DataUrl::parse(&data_url.to_string()).unwrap();
+
+        assert_eq!(data_url.media_type_no_default(), reparsed.media_type_no_default());
+        assert_eq!(data_url.charset_no_default(), reparsed.charset_no_default());
+        assert_eq!(data_url.content_encoding(), reparsed.content_encoding());
+        assert_eq!(data_url.parameters(), reparsed.parameters());
+        assert_eq!(data_url.is_base64_encoded(), reparsed.is_base64_encoded());
+        assert_eq!(data_url.data(), reparsed.data());
+        assert_eq!(data_url.fragment(), reparsed.fragment());
+    }
+});
@@ -0,0 +1,13 @@
+#![no_main]
+
+use dataurl::DataUrl;
+use libfuzzer_sys::fuzz_target;
+
+// Decoding arbitrary bytes in any charset must never panic
+fuzz_target!(|input: (&str, &[u8])| {
+    let (charset, data) = input;
+    let mut data_url: DataUrl = DataUrl::from_bytes(data);
+    data_url.set_charset(Some(charset.to_string()));
+
+    data_url.text();
+});
@@ -0,0 +1,35 @@
+#![no_main]
+
+use dataurl::DataUrl;
+use libfuzzer_sys::fuzz_target;
+
+// Whatever the setters accept has to survive a trip through to_string() and parse()
+fuzz_target!(|input: (
+    Option<String>,
+    Option<String>,
+    Vec<(String, String)>,
+    bool,
+    &[u8],
+    Option<String>,
+)| {
+    let (media_type, charset, parameters, is_base64_encoded, data, fragment) = input;
+    let mut data_url: DataUrl = DataUrl::new();
+    data_url.set_media_type(media_type);
+    data_url.set_charset(charset);
+    for (name, value) in parameters {
+        data_url.set_parameter(&name, Some(value));
+    }
+    data_url.set_is_base64_encoded(is_base64_encoded);
+    data_url.set_data(data);
+    data_url.set_fragment(fragment);
+
+    let reparsed: DataUrl = DataUrl::parse(&data_url.to_string()).unwrap();
+
+    assert_eq!(data_url.media_type_no_default(), reparsed.media_type_no_default());
+    assert_eq!(data_url.charset_no_default(), reparsed.charset_no_default());
+    assert_eq!(data_url.content_encoding(), reparsed.content_encoding());
+    assert_eq!(data_url.parameters(), reparsed.parameters());
+    assert_eq!(data_url.is_base64_encoded(), reparsed.is_base64_encoded());
+    assert_eq!(data_url.data(), reparsed.data());
+    assert_eq!(data_url.fragment(), reparsed.fragment());
+});
@@ -0,0 +1,173 @@
+#ifndef DATAURL_H
+#define DATAURL_H
+
+/* Generated by cbindgen from src/capi.rs, do not edit by hand */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef enum {
+  DATAURL_OK = 0,
+  DATAURL_ERR_NULL_POINTER = 1,
+  DATAURL_ERR_INVALID_UTF8 = 2,
+  DATAURL_ERR_URL_PARSE = 3,
+  DATAURL_ERR_MALFORMED = 4,
+  DATAURL_ERR_BASE64_DECODE = 5,
+  DATAURL_ERR_INVALID_MEDIA_TYPE = 6,
+  DATAURL_ERR_INVALID_CHARSET = 7,
+  DATAURL_ERR_LIMIT_EXCEEDED = 8,
+  DATAURL_ERR_INVALID_PARAMETER = 9,
+  DATAURL_ERR_INVALID_CHARACTER = 10,
+  DATAURL_ERR_DECOMPRESSION = 11,
+} dataurl_error_t;
+
+typedef struct dataurl_t dataurl_t;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Creates a new empty data URL; free it with dataurl_free().
+ */
+dataurl_t *dataurl_new(void);
+
+/**
+ * Parses a NUL-terminated string; on success *out receives a handle to free with dataurl_free().
+ *
+ * # Safety
+ *
+ * `input` must be a valid NUL-terminated string, `out` must be a valid pointer.
+ */
+dataurl_error_t dataurl_parse(const char *input, dataurl_t **out);
+
+/**
+ * Frees a handle returned by dataurl_new() or dataurl_parse(); NULL is ignored.
+ *
+ * # Safety
+ *
+ * `data_url` must be NULL or a handle that hasn't been freed yet.
+ */
+void dataurl_free(dataurl_t *data_url);
+
+/**
+ * Sets media type, NULL unsets it.
+ *
+ * # Safety
+ *
+ * `data_url` must be a valid handle, `media_type` must be NULL or a valid NUL-terminated string.
+ */
+dataurl_error_t dataurl_set_media_type(dataurl_t *data_url, const char *media_type);
+
+/**
+ * Sets charset, NULL unsets it.
+ *
+ * # Safety
+ *
+ * `data_url` must be a valid handle, `charset` must be NULL or a valid NUL-terminated string.
+ */
+dataurl_error_t dataurl_set_charset(dataurl_t *data_url, const char *charset);
+
+/**
+ * Toggles base64 encoding.
+ *
+ * # Safety
+ *
+ * `data_url` must be a valid handle.
+ */
+dataurl_error_t dataurl_set_base64(dataurl_t *data_url, bool is_base64_encoded);
+
+/**
+ * Copies `len` bytes into the data URL's payload.
+ *
+ * # Safety
+ *
+ * `data_url` must be a valid handle, `data` must point to `len` readable bytes (or be NULL if `len` is 0).
+ */
+dataurl_error_t dataurl_set_data(dataurl_t *data_url, const uint8_t *data, size_t len);
+
+/**
+ * Sets fragment, NULL unsets it.
+ *
+ * # Safety
+ *
+ * `data_url` must be a valid handle, `fragment` must be NULL or a valid NUL-terminated string.
+ */
+dataurl_error_t dataurl_set_fragment(dataurl_t *data_url, const char *fragment);
+
+/**
+ * Returns media type (text/plain if unset); free it with dataurl_string_free().
+ *
+ * # Safety
+ *
+ * `data_url` must be NULL or a valid handle.
+ */
+char *dataurl_media_type(const dataurl_t *data_url);
+
+/**
+ * Returns charset (US-ASCII if unset); free it with dataurl_string_free().
+ *
+ * # Safety
+ *
+ * `data_url` must be NULL or a valid handle.
+ */
+char *dataurl_charset(const dataurl_t *data_url);
+
+/**
+ * Tells whether the data URL is base64-encoded.
+ *
+ * # Safety
+ *
+ * `data_url` must be NULL or a valid handle.
+ */
+bool dataurl_is_base64(const dataurl_t *data_url);
+
+/**
+ * Returns a pointer to the payload, valid until the handle is modified or freed; *len receives its length.
+ *
+ * # Safety
+ *
+ * `data_url` must be NULL or a valid handle, `len` must be a valid pointer.
+ */
+const uint8_t *dataurl_data(const dataurl_t *data_url, size_t *len);
+
+/**
+ * Returns fragment, or NULL if there's none; free it with dataurl_string_free().
+ *
+ * # Safety
+ *
+ * `data_url` must be NULL or a valid handle.
+ */
+char *dataurl_fragment(const dataurl_t *data_url);
+
+/**
+ * Serializes the data URL; free the result with dataurl_string_free().
+ *
+ * # Safety
+ *
+ * `data_url` must be NULL or a valid handle.
+ */
+char *dataurl_to_string(const dataurl_t *data_url);
+
+/**
+ * Frees a string returned by this library; NULL is ignored.
+ *
+ * # Safety
+ *
+ * `s` must be NULL or a string returned by this library that hasn't been freed yet.
+ */
+void dataurl_string_free(char *s);
+
+/**
+ * Returns a static, human-readable description of an error code, "unknown error" for unknown ones.
+ */
+const char *dataurl_error_message(int error);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* DATAURL_H */
@@ -0,0 +1,93 @@
+use alloc::string::ToString;
+use bytes::Bytes;
+use core::fmt;
+use encoding_rs::Encoding;
+
+use crate::{validate_media_type, DataUrl};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DataUrlBuildError {
+    InvalidMediaType,
+    InvalidCharset,
+}
+
+impl fmt::Display for DataUrlBuildError {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str(match self {
+            DataUrlBuildError::InvalidMediaType => "invalid media type",
+            DataUrlBuildError::InvalidCharset => "invalid charset",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DataUrlBuildError {}
+
+pub struct DataUrlBuilder {
+    data_url: DataUrl,
+}
+
+impl DataUrlBuilder {
+    pub fn new() -> DataUrlBuilder {
+        DataUrlBuilder {
+            data_url: DataUrl::new(),
+        }
+    }
+
+    pub fn media_type(mut self, media_type: &str) -> Result<Self, DataUrlBuildError> {
+        // Unlike set_media_type(), invalid input is an error rather than a silent reset
+        if media_type.trim().is_empty() || !validate_media_type(media_type) {
+            return Err(DataUrlBuildError::InvalidMediaType);
+        }
+
+        self.data_url.media_type = Some(media_type.trim().to_lowercase());
+        Ok(self)
+    }
+
+    pub fn charset(mut self, charset: &str) -> Result<Self, DataUrlBuildError> {
+        if let Some(e) = Encoding::for_label_no_replacement(charset.as_bytes()) {
+            self.data_url.charset = Some(e.name().to_string());
+            Ok(self)
+        } else {
+            Err(DataUrlBuildError::InvalidCharset)
+        }
+    }
+
+    pub fn base64(mut self, is_base64_encoded: bool) -> Self {
+        self.data_url.is_base64_encoded = is_base64_encoded;
+        self
+    }
+
+    pub fn fragment(mut self, fragment: &str) -> Self {
+        self.data_url.fragment = Some(fragment.to_string());
+        self
+    }
+
+    // Vec<u8> and Bytes get taken over without copying them
+    pub fn data<D: Into<Bytes>>(mut self, data: D) -> Self {
+        self.data_url.data = data.into();
+        self
+    }
+
+    // Same magic bytes from_file() goes by, binary data getting base64-encoded; call it after data()
+    #[cfg(feature = "std")]
+    pub fn sniff_media_type(mut self) -> Self {
+        if let Some(media_type) = self.data_url.sniffed_media_type() {
+            self.data_url.media_type = Some(media_type.to_string());
+            if self.data_url.is_binary() {
+                self.data_url.is_base64_encoded = true;
+            }
+        }
+        self
+    }
+
+    pub fn build(self) -> DataUrl {
+        self.data_url
+    }
+}
+
+impl Default for DataUrlBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,49 @@
+use alloc::string::String;
+
+use crate::{DataUrl, DEFAULT_MEDIA_TYPE};
+
+// Name encoding_rs uses for US-ASCII, the default charset of data URLs
+const DEFAULT_CHARSET_NAME: &str = "windows-1252";
+
+impl DataUrl {
+    pub fn canonicalize(&mut self) {
+        // Media types are case-insensitive, text/plain is implied when omitted
+        if let Some(mt) = &self.media_type {
+            let mt: String = mt.trim().to_lowercase();
+            self.media_type = if mt == DEFAULT_MEDIA_TYPE {
+                None
+            } else {
+                Some(mt)
+            };
+        }
+
+        // Charset names are already normalized by encoding_rs, only the default needs to go
+        if self.charset.as_deref() == Some(DEFAULT_CHARSET_NAME) {
+            self.charset = None;
+        }
+
+        // Binary payloads are always base64-encoded, textual ones are always percent-encoded
+        self.is_base64_encoded = self.is_binary() || self.parameter("content-encoding").is_some();
+
+        // Nothing of the original serialization is canonical
+        self.original = None;
+    }
+
+    pub fn to_canonical_string(&self) -> String {
+        let mut data_url: DataUrl = self.clone();
+        data_url.canonicalize();
+
+        let mut result: String = String::from("data:");
+        result += &data_url.meta_data_to_string(true);
+        result += ",";
+        // The payload is written out as-is, using the standard base64 alphabet
+        result += &data_url.data_to_string();
+
+        if let Some(f) = data_url.fragment_to_string() {
+            result += "#";
+            result += &f;
+        }
+
+        result
+    }
+}
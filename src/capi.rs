@@ -0,0 +1,372 @@
+#![allow(non_camel_case_types)]
+
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_char, c_int};
+use std::ptr;
+use std::slice;
+
+use crate::{DataUrl, DataUrlParseError};
+
+// Every fallible function returns one of these, DATAURL_OK being zero
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum dataurl_error_t {
+    DATAURL_OK = 0,
+    DATAURL_ERR_NULL_POINTER = 1,
+    DATAURL_ERR_INVALID_UTF8 = 2,
+    DATAURL_ERR_URL_PARSE = 3,
+    DATAURL_ERR_MALFORMED = 4,
+    DATAURL_ERR_BASE64_DECODE = 5,
+    DATAURL_ERR_INVALID_MEDIA_TYPE = 6,
+    DATAURL_ERR_INVALID_CHARSET = 7,
+    DATAURL_ERR_LIMIT_EXCEEDED = 8,
+    DATAURL_ERR_INVALID_PARAMETER = 9,
+    DATAURL_ERR_INVALID_CHARACTER = 10,
+    DATAURL_ERR_DECOMPRESSION = 11,
+}
+
+impl dataurl_error_t {
+    const ALL: [dataurl_error_t; 12] = [
+        dataurl_error_t::DATAURL_OK,
+        dataurl_error_t::DATAURL_ERR_NULL_POINTER,
+        dataurl_error_t::DATAURL_ERR_INVALID_UTF8,
+        dataurl_error_t::DATAURL_ERR_URL_PARSE,
+        dataurl_error_t::DATAURL_ERR_MALFORMED,
+        dataurl_error_t::DATAURL_ERR_BASE64_DECODE,
+        dataurl_error_t::DATAURL_ERR_INVALID_MEDIA_TYPE,
+        dataurl_error_t::DATAURL_ERR_INVALID_CHARSET,
+        dataurl_error_t::DATAURL_ERR_LIMIT_EXCEEDED,
+        dataurl_error_t::DATAURL_ERR_INVALID_PARAMETER,
+        dataurl_error_t::DATAURL_ERR_INVALID_CHARACTER,
+        dataurl_error_t::DATAURL_ERR_DECOMPRESSION,
+    ];
+
+    // C lets any int through where an enum is expected, so codes have to be checked before use
+    fn from_code(code: c_int) -> Option<dataurl_error_t> {
+        dataurl_error_t::ALL
+            .iter()
+            .copied()
+            .find(|error| *error as c_int == code)
+    }
+}
+
+impl From<DataUrlParseError> for dataurl_error_t {
+    fn from(err: DataUrlParseError) -> Self {
+        match err {
+            DataUrlParseError::UrlParseError => dataurl_error_t::DATAURL_ERR_URL_PARSE,
+            DataUrlParseError::MalformedDataUrlError => dataurl_error_t::DATAURL_ERR_MALFORMED,
+            DataUrlParseError::Base64DecodeError => dataurl_error_t::DATAURL_ERR_BASE64_DECODE,
+            DataUrlParseError::LimitExceeded => dataurl_error_t::DATAURL_ERR_LIMIT_EXCEEDED,
+            DataUrlParseError::InvalidMediaType => dataurl_error_t::DATAURL_ERR_INVALID_MEDIA_TYPE,
+            DataUrlParseError::InvalidParameter => dataurl_error_t::DATAURL_ERR_INVALID_PARAMETER,
+            DataUrlParseError::InvalidCharacter => dataurl_error_t::DATAURL_ERR_INVALID_CHARACTER,
+            DataUrlParseError::DecompressionError => dataurl_error_t::DATAURL_ERR_DECOMPRESSION,
+        }
+    }
+}
+
+// NULL stands for "unset", anything else has to be valid UTF-8
+unsafe fn optional_str(s: *const c_char) -> Result<Option<String>, dataurl_error_t> {
+    if s.is_null() {
+        return Ok(None);
+    }
+
+    CStr::from_ptr(s)
+        .to_str()
+        .map(|s| Some(s.to_string()))
+        .map_err(|_| dataurl_error_t::DATAURL_ERR_INVALID_UTF8)
+}
+
+fn to_c_string(s: &str) -> *mut c_char {
+    // Interior NUL bytes can't be represented, such strings get cut short
+    let s: &str = s.split('\0').next().unwrap_or("");
+    CString::new(s).unwrap().into_raw()
+}
+
+/// Creates a new empty data URL; free it with dataurl_free().
+#[no_mangle]
+pub extern "C" fn dataurl_new() -> *mut DataUrl {
+    Box::into_raw(Box::new(DataUrl::new()))
+}
+
+/// Parses a NUL-terminated string; on success *out receives a handle to free with dataurl_free().
+///
+/// # Safety
+///
+/// `input` must be a valid NUL-terminated string, `out` must be a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn dataurl_parse(
+    input: *const c_char,
+    out: *mut *mut DataUrl,
+) -> dataurl_error_t {
+    if input.is_null() || out.is_null() {
+        return dataurl_error_t::DATAURL_ERR_NULL_POINTER;
+    }
+
+    let input: &str = match CStr::from_ptr(input).to_str() {
+        Ok(input) => input,
+        Err(_) => return dataurl_error_t::DATAURL_ERR_INVALID_UTF8,
+    };
+
+    match DataUrl::parse(input) {
+        Ok(data_url) => {
+            *out = Box::into_raw(Box::new(data_url));
+            dataurl_error_t::DATAURL_OK
+        }
+        Err(err) => {
+            *out = ptr::null_mut();
+            err.into()
+        }
+    }
+}
+
+/// Frees a handle returned by dataurl_new() or dataurl_parse(); NULL is ignored.
+///
+/// # Safety
+///
+/// `data_url` must be NULL or a handle that hasn't been freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn dataurl_free(data_url: *mut DataUrl) {
+    if !data_url.is_null() {
+        drop(Box::from_raw(data_url));
+    }
+}
+
+/// Sets media type, NULL unsets it.
+///
+/// # Safety
+///
+/// `data_url` must be a valid handle, `media_type` must be NULL or a valid NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn dataurl_set_media_type(
+    data_url: *mut DataUrl,
+    media_type: *const c_char,
+) -> dataurl_error_t {
+    let data_url: &mut DataUrl = match data_url.as_mut() {
+        Some(data_url) => data_url,
+        None => return dataurl_error_t::DATAURL_ERR_NULL_POINTER,
+    };
+
+    match optional_str(media_type) {
+        Ok(media_type) => {
+            if data_url.set_media_type(media_type) {
+                dataurl_error_t::DATAURL_OK
+            } else {
+                dataurl_error_t::DATAURL_ERR_INVALID_MEDIA_TYPE
+            }
+        }
+        Err(err) => err,
+    }
+}
+
+/// Sets charset, NULL unsets it.
+///
+/// # Safety
+///
+/// `data_url` must be a valid handle, `charset` must be NULL or a valid NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn dataurl_set_charset(
+    data_url: *mut DataUrl,
+    charset: *const c_char,
+) -> dataurl_error_t {
+    let data_url: &mut DataUrl = match data_url.as_mut() {
+        Some(data_url) => data_url,
+        None => return dataurl_error_t::DATAURL_ERR_NULL_POINTER,
+    };
+
+    match optional_str(charset) {
+        Ok(charset) => {
+            if data_url.set_charset(charset) {
+                dataurl_error_t::DATAURL_OK
+            } else {
+                dataurl_error_t::DATAURL_ERR_INVALID_CHARSET
+            }
+        }
+        Err(err) => err,
+    }
+}
+
+/// Toggles base64 encoding.
+///
+/// # Safety
+///
+/// `data_url` must be a valid handle.
+#[no_mangle]
+pub unsafe extern "C" fn dataurl_set_base64(
+    data_url: *mut DataUrl,
+    is_base64_encoded: bool,
+) -> dataurl_error_t {
+    match data_url.as_mut() {
+        Some(data_url) => {
+            data_url.set_is_base64_encoded(is_base64_encoded);
+            dataurl_error_t::DATAURL_OK
+        }
+        None => dataurl_error_t::DATAURL_ERR_NULL_POINTER,
+    }
+}
+
+/// Copies `len` bytes into the data URL's payload.
+///
+/// # Safety
+///
+/// `data_url` must be a valid handle, `data` must point to `len` readable bytes (or be NULL if `len` is 0).
+#[no_mangle]
+pub unsafe extern "C" fn dataurl_set_data(
+    data_url: *mut DataUrl,
+    data: *const u8,
+    len: usize,
+) -> dataurl_error_t {
+    let data_url: &mut DataUrl = match data_url.as_mut() {
+        Some(data_url) => data_url,
+        None => return dataurl_error_t::DATAURL_ERR_NULL_POINTER,
+    };
+
+    if len == 0 {
+        data_url.set_data(&[]);
+    } else if data.is_null() {
+        return dataurl_error_t::DATAURL_ERR_NULL_POINTER;
+    } else {
+        data_url.set_data(slice::from_raw_parts(data, len));
+    }
+
+    dataurl_error_t::DATAURL_OK
+}
+
+/// Sets fragment, NULL unsets it.
+///
+/// # Safety
+///
+/// `data_url` must be a valid handle, `fragment` must be NULL or a valid NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn dataurl_set_fragment(
+    data_url: *mut DataUrl,
+    fragment: *const c_char,
+) -> dataurl_error_t {
+    let data_url: &mut DataUrl = match data_url.as_mut() {
+        Some(data_url) => data_url,
+        None => return dataurl_error_t::DATAURL_ERR_NULL_POINTER,
+    };
+
+    match optional_str(fragment) {
+        Ok(fragment) => {
+            data_url.set_fragment(fragment);
+            dataurl_error_t::DATAURL_OK
+        }
+        Err(err) => err,
+    }
+}
+
+/// Returns media type (text/plain if unset); free it with dataurl_string_free().
+///
+/// # Safety
+///
+/// `data_url` must be NULL or a valid handle.
+#[no_mangle]
+pub unsafe extern "C" fn dataurl_media_type(data_url: *const DataUrl) -> *mut c_char {
+    match data_url.as_ref() {
+        Some(data_url) => to_c_string(data_url.media_type()),
+        None => ptr::null_mut(),
+    }
+}
+
+/// Returns charset (US-ASCII if unset); free it with dataurl_string_free().
+///
+/// # Safety
+///
+/// `data_url` must be NULL or a valid handle.
+#[no_mangle]
+pub unsafe extern "C" fn dataurl_charset(data_url: *const DataUrl) -> *mut c_char {
+    match data_url.as_ref() {
+        Some(data_url) => to_c_string(data_url.charset()),
+        None => ptr::null_mut(),
+    }
+}
+
+/// Tells whether the data URL is base64-encoded.
+///
+/// # Safety
+///
+/// `data_url` must be NULL or a valid handle.
+#[no_mangle]
+pub unsafe extern "C" fn dataurl_is_base64(data_url: *const DataUrl) -> bool {
+    data_url
+        .as_ref()
+        .map(|data_url| data_url.is_base64_encoded())
+        .unwrap_or(false)
+}
+
+/// Returns a pointer to the payload, valid until the handle is modified or freed; *len receives its length.
+///
+/// # Safety
+///
+/// `data_url` must be NULL or a valid handle, `len` must be a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn dataurl_data(data_url: *const DataUrl, len: *mut usize) -> *const u8 {
+    match (data_url.as_ref(), len.is_null()) {
+        (Some(data_url), false) => {
+            *len = data_url.data().len();
+            data_url.data().as_ptr()
+        }
+        _ => ptr::null(),
+    }
+}
+
+/// Returns fragment, or NULL if there's none; free it with dataurl_string_free().
+///
+/// # Safety
+///
+/// `data_url` must be NULL or a valid handle.
+#[no_mangle]
+pub unsafe extern "C" fn dataurl_fragment(data_url: *const DataUrl) -> *mut c_char {
+    match data_url.as_ref().and_then(|data_url| data_url.fragment()) {
+        Some(fragment) => to_c_string(&fragment),
+        None => ptr::null_mut(),
+    }
+}
+
+/// Serializes the data URL; free the result with dataurl_string_free().
+///
+/// # Safety
+///
+/// `data_url` must be NULL or a valid handle.
+#[no_mangle]
+pub unsafe extern "C" fn dataurl_to_string(data_url: *const DataUrl) -> *mut c_char {
+    match data_url.as_ref() {
+        Some(data_url) => to_c_string(&data_url.to_string()),
+        None => ptr::null_mut(),
+    }
+}
+
+/// Frees a string returned by this library; NULL is ignored.
+///
+/// # Safety
+///
+/// `s` must be NULL or a string returned by this library that hasn't been freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn dataurl_string_free(s: *mut c_char) {
+    if !s.is_null() {
+        drop(CString::from_raw(s));
+    }
+}
+
+/// Returns a static, human-readable description of an error code, "unknown error" for unknown ones.
+#[no_mangle]
+pub extern "C" fn dataurl_error_message(error: c_int) -> *const c_char {
+    let message: &'static [u8] = match dataurl_error_t::from_code(error) {
+        None => b"unknown error\0",
+        Some(dataurl_error_t::DATAURL_OK) => b"no error\0",
+        Some(dataurl_error_t::DATAURL_ERR_NULL_POINTER) => b"null pointer\0",
+        Some(dataurl_error_t::DATAURL_ERR_INVALID_UTF8) => b"invalid UTF-8\0",
+        Some(dataurl_error_t::DATAURL_ERR_URL_PARSE) => b"unable to parse URL\0",
+        Some(dataurl_error_t::DATAURL_ERR_MALFORMED) => b"malformed data URL\0",
+        Some(dataurl_error_t::DATAURL_ERR_BASE64_DECODE) => b"unable to decode base64\0",
+        Some(dataurl_error_t::DATAURL_ERR_INVALID_MEDIA_TYPE) => b"invalid media type\0",
+        Some(dataurl_error_t::DATAURL_ERR_INVALID_CHARSET) => b"invalid charset\0",
+        Some(dataurl_error_t::DATAURL_ERR_LIMIT_EXCEEDED) => b"limit exceeded\0",
+        Some(dataurl_error_t::DATAURL_ERR_INVALID_PARAMETER) => b"invalid parameter\0",
+        Some(dataurl_error_t::DATAURL_ERR_INVALID_CHARACTER) => b"invalid character\0",
+        Some(dataurl_error_t::DATAURL_ERR_DECOMPRESSION) => b"unable to decompress data\0",
+    };
+
+    message.as_ptr() as *const c_char
+}
@@ -0,0 +1,131 @@
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+#[cfg(not(feature = "simd"))]
+use base64::{
+    engine::general_purpose::{STANDARD, URL_SAFE},
+    Engine as _,
+};
+#[cfg(feature = "simd")]
+use base64_simd::AsOut;
+use core::fmt;
+use percent_encoding::percent_decode_str;
+
+const HEX_DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+const CHUNK_LEN: usize = 1024;
+
+// Same output as percent_encode(data, NON_ALPHANUMERIC), written into one preallocated buffer
+pub(crate) fn percent_encode(data: &[u8]) -> String {
+    let mut result: String = String::with_capacity(data.len() * 3);
+    write_percent_encoded(&mut result, data).unwrap();
+    result
+}
+
+// Encodes one chunk at a time into a buffer on the stack, for callers with somewhere else to put the output
+pub(crate) fn write_percent_encoded<W: fmt::Write>(output: &mut W, data: &[u8]) -> fmt::Result {
+    let mut buffer: [u8; CHUNK_LEN * 4] = [0; CHUNK_LEN * 4];
+
+    for chunk in data.chunks(CHUNK_LEN) {
+        let mut len: usize = 0;
+        for &b in chunk {
+            if b.is_ascii_alphanumeric() {
+                buffer[len] = b;
+                len += 1;
+            } else {
+                buffer[len] = b'%';
+                buffer[len + 1] = HEX_DIGITS[(b >> 4) as usize];
+                buffer[len + 2] = HEX_DIGITS[(b & 0x0f) as usize];
+                len += 3;
+            }
+        }
+        output.write_str(core::str::from_utf8(&buffer[..len]).unwrap())?;
+    }
+
+    Ok(())
+}
+
+// Chunks are a multiple of 3 bytes long, so that there's no padding until the very end
+pub(crate) fn write_base64<W: fmt::Write>(output: &mut W, data: &[u8]) -> fmt::Result {
+    let mut buffer: [u8; CHUNK_LEN * 4] = [0; CHUNK_LEN * 4];
+
+    for chunk in data.chunks(CHUNK_LEN * 3) {
+        #[cfg(feature = "simd")]
+        let encoded: &str = base64_simd::STANDARD.encode_as_str(chunk, buffer[..].as_out());
+        #[cfg(not(feature = "simd"))]
+        let encoded: &str = {
+            let len: usize = STANDARD.encode_slice(chunk, &mut buffer).unwrap();
+            core::str::from_utf8(&buffer[..len]).unwrap()
+        };
+        output.write_str(encoded)?;
+    }
+
+    Ok(())
+}
+
+// Splits ASCII text (e.g. base64) into lines no longer than given width, each one terminated by CRLF
+pub(crate) fn wrap_lines(text: &str, width: usize) -> String {
+    let mut result: String = String::with_capacity(text.len() + text.len() / width.max(1) * 2 + 2);
+
+    for line in text.as_bytes().chunks(width.max(1)) {
+        result.push_str(core::str::from_utf8(line).unwrap());
+        result.push_str("\r\n");
+    }
+
+    result
+}
+
+// Fewest bytes percent-decoding could turn the input into, each %XX standing for one of them
+pub(crate) fn percent_decoded_len_min(input: &str) -> usize {
+    input.len()
+        - 2 * input
+            .bytes()
+            .filter(|b| *b == b'%')
+            .count()
+            .min(input.len() / 3)
+}
+
+// How many bytes valid base64 decodes to, known without decoding it
+pub(crate) fn base64_decoded_len(data: &[u8]) -> usize {
+    data.iter()
+        .filter(|b| **b != b'=' && !b.is_ascii_whitespace())
+        .count()
+        * 3
+        / 4
+}
+
+// Borrows the input if there's nothing to decode
+pub(crate) fn percent_decode(input: &str) -> Cow<'_, [u8]> {
+    percent_decode_str(input).into()
+}
+
+// Always the standard alphabet, the only one forgiving-base64 in browsers (fetch(), atob(), etc) accepts
+#[cfg(feature = "simd")]
+pub(crate) fn base64_encode(data: &[u8]) -> String {
+    base64_simd::STANDARD.encode_to_string(data)
+}
+
+#[cfg(not(feature = "simd"))]
+pub(crate) fn base64_encode(data: &[u8]) -> String {
+    STANDARD.encode(data)
+}
+
+// Accepts both the URL-safe and the standard alphabet, but not a mix of the two
+pub(crate) fn base64_decode(data: &[u8]) -> Option<Vec<u8>> {
+    // Telling alphabets apart upfront is cheaper than a failed attempt at decoding
+    let url_safe: bool = !data.iter().any(|b| *b == b'+' || *b == b'/');
+
+    #[cfg(feature = "simd")]
+    let decoded = if url_safe {
+        base64_simd::URL_SAFE.decode_to_vec(data)
+    } else {
+        base64_simd::STANDARD.decode_to_vec(data)
+    };
+    #[cfg(not(feature = "simd"))]
+    let decoded = if url_safe {
+        URL_SAFE.decode(data)
+    } else {
+        STANDARD.decode(data)
+    };
+
+    decoded.ok()
+}
@@ -0,0 +1,172 @@
+#[cfg(feature = "compression")]
+use std::io::{self, Read, Write};
+
+#[cfg(feature = "compression")]
+use crate::{DataUrl, DataUrlParseError, ParseOptions};
+
+// Values of the content-encoding parameter, named the same way as in HTTP and DecompressionStream
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ContentEncoding {
+    Gzip,
+    Deflate,
+    Brotli,
+}
+
+impl ContentEncoding {
+    pub fn from_name(name: &str) -> Option<ContentEncoding> {
+        match name.trim().to_lowercase().as_str() {
+            "gzip" => Some(ContentEncoding::Gzip),
+            "deflate" => Some(ContentEncoding::Deflate),
+            "br" => Some(ContentEncoding::Brotli),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ContentEncoding::Gzip => "gzip",
+            ContentEncoding::Deflate => "deflate",
+            ContentEncoding::Brotli => "br",
+        }
+    }
+}
+
+#[cfg(feature = "compression")]
+pub(crate) fn compress(data: &[u8], content_encoding: ContentEncoding) -> io::Result<Vec<u8>> {
+    match content_encoding {
+        ContentEncoding::Gzip => {
+            let mut encoder =
+                flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::best());
+            encoder.write_all(data)?;
+            encoder.finish()
+        }
+        ContentEncoding::Deflate => {
+            // DecompressionStream("deflate") expects the zlib wrapper, not raw deflate
+            let mut encoder =
+                flate2::write::ZlibEncoder::new(Vec::new(), flate2::Compression::best());
+            encoder.write_all(data)?;
+            encoder.finish()
+        }
+        ContentEncoding::Brotli => {
+            let mut output: Vec<u8> = Vec::new();
+            {
+                let mut encoder = brotli::CompressorWriter::new(&mut output, 4096, 11, 22);
+                encoder.write_all(data)?;
+            }
+            Ok(output)
+        }
+    }
+}
+
+// Reading one byte past the limit is enough to tell that it's been exceeded
+#[cfg(feature = "compression")]
+fn read_decompressed(
+    data: &[u8],
+    content_encoding: ContentEncoding,
+    max_len: Option<usize>,
+) -> io::Result<Vec<u8>> {
+    let mut output: Vec<u8> = Vec::new();
+    let mut decoder: Box<dyn Read + '_> = match content_encoding {
+        ContentEncoding::Gzip => Box::new(flate2::read::GzDecoder::new(data)),
+        ContentEncoding::Deflate => Box::new(flate2::read::ZlibDecoder::new(data)),
+        ContentEncoding::Brotli => Box::new(brotli::Decompressor::new(data, 4096)),
+    };
+
+    match max_len {
+        Some(max_len) => decoder.take(max_len as u64 + 1).read_to_end(&mut output)?,
+        None => decoder.read_to_end(&mut output)?,
+    };
+
+    Ok(output)
+}
+
+#[cfg(feature = "compression")]
+pub(crate) fn decompress(
+    data: &[u8],
+    content_encoding: ContentEncoding,
+    max_len: Option<usize>,
+) -> io::Result<Vec<u8>> {
+    let output: Vec<u8> = read_decompressed(data, content_encoding, max_len)?;
+
+    if max_len.is_some_and(|max_len| output.len() > max_len) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "decompressed data exceeds size limit",
+        ));
+    }
+
+    Ok(output)
+}
+
+// Payloads labeled with a content-encoding unknown to ContentEncoding can't be decompressed, nor compressed again
+#[cfg(feature = "compression")]
+fn unsupported_content_encoding() -> io::Error {
+    io::Error::new(io::ErrorKind::Unsupported, "unsupported content-encoding")
+}
+
+#[cfg(feature = "compression")]
+impl DataUrl {
+    pub fn compress(&mut self, content_encoding: ContentEncoding) -> io::Result<()> {
+        // Never compress twice, the parameter can only describe one layer
+        self.decompress()?;
+
+        let compressed: Vec<u8> = compress(&self.data, content_encoding)?;
+        self.set_data_owned(compressed);
+        self.set_content_encoding(Some(content_encoding));
+
+        // Compressed payload is binary no matter what the media type says
+        self.set_is_base64_encoded(true);
+
+        Ok(())
+    }
+
+    pub fn decompress(&mut self) -> io::Result<()> {
+        self.decompress_inner(None)
+    }
+
+    // Guards against decompression bombs in payloads coming from untrusted sources
+    pub fn decompress_with_limit(&mut self, max_len: usize) -> io::Result<()> {
+        self.decompress_inner(Some(max_len))
+    }
+
+    fn decompress_inner(&mut self, max_len: Option<usize>) -> io::Result<()> {
+        if let Some(content_encoding) = self.content_encoding {
+            let decompressed: Vec<u8> = decompress(&self.data, content_encoding, max_len)?;
+            self.set_data_owned(decompressed);
+            self.set_content_encoding(None);
+        } else if self.parameter("content-encoding").is_some() {
+            return Err(unsupported_content_encoding());
+        }
+
+        Ok(())
+    }
+
+    // Done by parse_with_options() when asked to, max_decoded_len applying to the decompressed payload
+    pub(crate) fn decompress_with_options(
+        &mut self,
+        options: &ParseOptions,
+    ) -> Result<(), DataUrlParseError> {
+        if let Some(content_encoding) = self.content_encoding {
+            let decompressed: Vec<u8> =
+                read_decompressed(&self.data, content_encoding, options.max_decoded_len)
+                    .map_err(|_| DataUrlParseError::DecompressionError)?;
+            options.check_decoded_len(decompressed.len())?;
+            self.set_data_owned(decompressed);
+            self.set_content_encoding(None);
+        } else if self.parameter("content-encoding").is_some() {
+            return Err(DataUrlParseError::DecompressionError);
+        }
+
+        Ok(())
+    }
+
+    pub fn decompressed_data(&self) -> io::Result<Vec<u8>> {
+        match self.content_encoding {
+            Some(content_encoding) => decompress(&self.data, content_encoding, None),
+            None if self.parameter("content-encoding").is_some() => {
+                Err(unsupported_content_encoding())
+            }
+            None => Ok(self.data.to_vec()),
+        }
+    }
+}
@@ -0,0 +1,115 @@
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::DataUrl;
+
+#[derive(Clone, Debug, Default)]
+pub struct FontFaceOptions {
+    pub family: String,          // Name the font gets referred to by in font-family
+    pub weight: Option<String>,  // 400, bold, 100 900 (variable fonts), etc
+    pub style: Option<String>,   // normal, italic or oblique
+    pub display: Option<String>, // auto, block, swap, fallback or optional
+}
+
+// Hint for the browser to skip fonts it can't use without downloading them first, which data URLs never need anyway
+pub(crate) fn font_format(media_type: &str) -> Option<&'static str> {
+    match media_type {
+        "font/woff2" | "application/font-woff2" => Some("woff2"),
+        "font/woff" | "application/font-woff" => Some("woff"),
+        "font/ttf" | "font/sfnt" | "application/x-font-ttf" => Some("truetype"),
+        "font/otf" | "application/x-font-opentype" => Some("opentype"),
+        "font/collection" => Some("collection"),
+        _ => None,
+    }
+}
+
+pub(crate) fn escape_string(value: &str) -> String {
+    // Line breaks can't appear in CSS strings as is
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\a ")
+        .replace('\r', "\\d ")
+}
+
+// normal, bold, bolder, lighter, or one or two (variable fonts) numbers from 1 to 1000
+fn is_font_weight(value: &str) -> bool {
+    let value: &str = value.trim();
+    let numbers: Vec<&str> = value.split_ascii_whitespace().collect();
+
+    ["normal", "bold", "bolder", "lighter"].contains(&value)
+        || (!numbers.is_empty()
+            && numbers.len() <= 2
+            && numbers
+                .iter()
+                .all(|n| n.parse::<f64>().is_ok_and(|n| (1.0..=1000.0).contains(&n))))
+}
+
+// normal, italic, or oblique followed by up to two angles (10deg, -0.5turn, etc)
+fn is_font_style(value: &str) -> bool {
+    let mut words = value.split_ascii_whitespace();
+
+    match words.next() {
+        Some("normal") | Some("italic") => words.next().is_none(),
+        Some("oblique") => {
+            let angles: Vec<&str> = words.collect();
+            angles.len() <= 2
+                && angles.iter().all(|angle| {
+                    ["deg", "grad", "rad", "turn"].iter().any(|unit| {
+                        angle
+                            .strip_suffix(unit)
+                            .is_some_and(|number| number.parse::<f64>().is_ok())
+                    })
+                })
+        }
+        _ => false,
+    }
+}
+
+fn is_font_display(value: &str) -> bool {
+    ["auto", "block", "swap", "fallback", "optional"].contains(&value.trim())
+}
+
+impl FontFaceOptions {
+    // Name of the first descriptor whose value doesn't match its CSS grammar, values go into CSS as is
+    pub fn invalid_descriptor(&self) -> Option<&'static str> {
+        if self.weight.as_deref().is_some_and(|v| !is_font_weight(v)) {
+            Some("font-weight")
+        } else if self.style.as_deref().is_some_and(|v| !is_font_style(v)) {
+            Some("font-style")
+        } else if self.display.as_deref().is_some_and(|v| !is_font_display(v)) {
+            Some("font-display")
+        } else {
+            None
+        }
+    }
+}
+
+impl DataUrl {
+    pub fn to_font_face(&self, options: &FontFaceOptions) -> String {
+        let mut output: String = format!(
+            "@font-face {{\n  font-family: \"{}\";\n  src: url(\"{}\")",
+            escape_string(&options.family),
+            escape_string(&self.to_browser_string())
+        );
+
+        if let Some(format) = font_format(self.media_type()) {
+            output += &format!(" format(\"{}\")", format);
+        }
+        output += ";\n";
+        // Invalid values get left out, they could otherwise break out of the rule
+        if let Some(weight) = options.weight.as_deref().filter(|v| is_font_weight(v)) {
+            output += &format!("  font-weight: {};\n", weight.trim());
+        }
+        if let Some(style) = options.style.as_deref().filter(|v| is_font_style(v)) {
+            output += &format!("  font-style: {};\n", style.trim());
+        }
+        if let Some(display) = options.display.as_deref().filter(|v| is_font_display(v)) {
+            output += &format!("  font-display: {};\n", display.trim());
+        }
+        output += "}";
+
+        output
+    }
+}
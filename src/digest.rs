@@ -0,0 +1,48 @@
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use sha2::{Digest, Sha256, Sha384, Sha512};
+
+use crate::DataUrl;
+
+// Hash functions allowed by the Subresource Integrity specification
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Algorithm {
+    Sha256,
+    Sha384,
+    Sha512,
+}
+
+impl Algorithm {
+    pub fn from_name(name: &str) -> Option<Algorithm> {
+        match name.to_lowercase().as_str() {
+            "sha256" => Some(Algorithm::Sha256),
+            "sha384" => Some(Algorithm::Sha384),
+            "sha512" => Some(Algorithm::Sha512),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Algorithm::Sha256 => "sha256",
+            Algorithm::Sha384 => "sha384",
+            Algorithm::Sha512 => "sha512",
+        }
+    }
+}
+
+impl DataUrl {
+    pub fn digest(&self, algorithm: Algorithm) -> String {
+        // Hash is computed over the decoded payload, the same bytes a browser would check;
+        // a compressed payload stays compressed, browsers don't undo content-encoding of data URLs
+        let hash: Vec<u8> = match algorithm {
+            Algorithm::Sha256 => Sha256::digest(&self.data).to_vec(),
+            Algorithm::Sha384 => Sha384::digest(&self.data).to_vec(),
+            Algorithm::Sha512 => Sha512::digest(&self.data).to_vec(),
+        };
+
+        format!("{}-{}", algorithm.name(), STANDARD.encode(hash))
+    }
+}
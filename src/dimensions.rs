@@ -0,0 +1,183 @@
+use core::convert::TryInto;
+
+use crate::DataUrl;
+
+fn u16_be(data: &[u8], offset: usize) -> Option<u32> {
+    let bytes = data.get(offset..offset + 2)?;
+    Some(u16::from_be_bytes([bytes[0], bytes[1]]) as u32)
+}
+
+fn u16_le(data: &[u8], offset: usize) -> Option<u32> {
+    let bytes = data.get(offset..offset + 2)?;
+    Some(u16::from_le_bytes([bytes[0], bytes[1]]) as u32)
+}
+
+fn u24_le(data: &[u8], offset: usize) -> Option<u32> {
+    let bytes = data.get(offset..offset + 3)?;
+    Some(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], 0]))
+}
+
+fn png_dimensions(data: &[u8]) -> Option<(u32, u32)> {
+    // IHDR is always the first chunk
+    if data.get(12..16)? != b"IHDR" {
+        return None;
+    }
+    let width = u32::from_be_bytes(data.get(16..20)?.try_into().ok()?);
+    let height = u32::from_be_bytes(data.get(20..24)?.try_into().ok()?);
+
+    Some((width, height))
+}
+
+fn gif_dimensions(data: &[u8]) -> Option<(u32, u32)> {
+    Some((u16_le(data, 6)?, u16_le(data, 8)?))
+}
+
+fn jpeg_dimensions(data: &[u8]) -> Option<(u32, u32)> {
+    let mut offset: usize = 2;
+
+    // Walk segments until the start of frame, which is where dimensions are
+    loop {
+        while *data.get(offset)? != 0xff {
+            offset += 1;
+        }
+        while *data.get(offset)? == 0xff {
+            offset += 1;
+        }
+        let marker: u8 = data[offset];
+        offset += 1;
+
+        match marker {
+            0xc0..=0xc3 | 0xc5..=0xc7 | 0xc9..=0xcb | 0xcd..=0xcf => {
+                return Some((u16_be(data, offset + 5)?, u16_be(data, offset + 3)?));
+            }
+            // Markers without payload
+            0x01 | 0xd0..=0xd8 => {}
+            0xd9 | 0xda => return None,
+            _ => offset += u16_be(data, offset)? as usize,
+        }
+    }
+}
+
+fn webp_dimensions(data: &[u8]) -> Option<(u32, u32)> {
+    match data.get(12..16)? {
+        // Lossy, dimensions are in the key frame header
+        b"VP8 " => {
+            if data.get(23..26)? != b"\x9d\x01\x2a" {
+                return None;
+            }
+            Some((u16_le(data, 26)? & 0x3fff, u16_le(data, 28)? & 0x3fff))
+        }
+        // Lossless, 14 bits per dimension, both stored minus one
+        b"VP8L" => {
+            if *data.get(20)? != 0x2f {
+                return None;
+            }
+            let bits = u32::from_le_bytes(data.get(21..25)?.try_into().ok()?);
+            Some(((bits & 0x3fff) + 1, ((bits >> 14) & 0x3fff) + 1))
+        }
+        // Extended, canvas size stored minus one
+        b"VP8X" => Some((u24_le(data, 24)? + 1, u24_le(data, 27)? + 1)),
+        _ => None,
+    }
+}
+
+// Finds attribute value within a tag, making sure not to match the tail of another name (stroke-width, etc)
+fn svg_attribute<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+    let mut rest: &str = tag;
+
+    while let Some(position) = rest.find(name) {
+        let preceded_by_space: bool = rest[..position].ends_with(|c: char| c.is_ascii_whitespace());
+        let after: &str = rest[position + name.len()..].trim_start();
+        rest = &rest[position + name.len()..];
+
+        if !preceded_by_space {
+            continue;
+        }
+        if let Some(after) = after.strip_prefix('=') {
+            let after: &str = after.trim_start();
+            let quote: char = after.chars().next().filter(|c| *c == '"' || *c == '\'')?;
+            let value: &str = &after[1..];
+            return value.find(quote).map(|end| &value[..end]);
+        }
+    }
+
+    None
+}
+
+// Only absolute lengths count, percentages and relative units depend on where the image ends up
+fn svg_length(value: &str) -> Option<u32> {
+    let value: &str = value.trim();
+    let number: f64 = value
+        .strip_suffix("px")
+        .unwrap_or(value)
+        .trim_end()
+        .parse()
+        .ok()?;
+
+    if number.is_finite() && number > 0.0 {
+        Some((number + 0.5) as u32)
+    } else {
+        None
+    }
+}
+
+fn svg_dimensions(data: &[u8]) -> Option<(u32, u32)> {
+    // The root element is expected to be close to the beginning
+    let head: &[u8] = &data[..data.len().min(4096)];
+    let text: &str = match core::str::from_utf8(head) {
+        Ok(text) => text,
+        Err(err) => core::str::from_utf8(&head[..err.valid_up_to()]).ok()?,
+    };
+    let start: usize = text.find("<svg")?;
+    let tag: &str = &text[start..start + text[start..].find('>')?];
+
+    match (
+        svg_attribute(tag, "width").and_then(svg_length),
+        svg_attribute(tag, "height").and_then(svg_length),
+    ) {
+        (Some(width), Some(height)) => Some((width, height)),
+        // Without absolute width and height, the viewBox defines the intrinsic size
+        _ => {
+            let view_box: &str = svg_attribute(tag, "viewBox")?;
+            let mut numbers = view_box
+                .split(|c: char| c == ',' || c.is_ascii_whitespace())
+                .filter(|n| !n.is_empty())
+                .skip(2);
+            Some((svg_length(numbers.next()?)?, svg_length(numbers.next()?)?))
+        }
+    }
+}
+
+fn is_svg(data: &[u8]) -> bool {
+    let head: &[u8] = &data[..data.len().min(4096)];
+    let head: &[u8] = head.strip_prefix(b"\xef\xbb\xbf").unwrap_or(head);
+    let start: usize = head
+        .iter()
+        .position(|b| !b.is_ascii_whitespace())
+        .unwrap_or(head.len());
+    let head: &[u8] = &head[start..];
+
+    head.starts_with(b"<svg")
+        || (head.starts_with(b"<?xml") && head.windows(4).any(|w| w == b"<svg"))
+}
+
+impl DataUrl {
+    // Looks at the payload itself, the media type doesn't have to be set (or correct)
+    pub fn image_dimensions(&self) -> Option<(u32, u32)> {
+        let data: &[u8] = self.data();
+
+        if data.starts_with(b"\x89PNG\r\n\x1a\n") {
+            png_dimensions(data)
+        } else if data.starts_with(b"GIF87a") || data.starts_with(b"GIF89a") {
+            gif_dimensions(data)
+        } else if data.starts_with(b"\xff\xd8\xff") {
+            jpeg_dimensions(data)
+        } else if data.starts_with(b"RIFF") && data.get(8..12) == Some(b"WEBP") {
+            webp_dimensions(data)
+        } else if is_svg(data) {
+            svg_dimensions(data)
+        } else {
+            None
+        }
+    }
+}
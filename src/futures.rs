@@ -0,0 +1,4 @@
+use futures_io::{AsyncRead, AsyncWrite};
+use futures_util::io::{AsyncReadExt, AsyncWriteExt};
+
+crate::stream::async_io!();
@@ -0,0 +1,272 @@
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use dataurl::DataUrl;
+use image::{imageops::FilterType, RgbaImage};
+use std::env;
+use std::fmt::Write as _;
+
+// Kitty wants payloads split into chunks of at most 4096 bytes
+const KITTY_CHUNK_SIZE: usize = 4096;
+// Sixel output grows fast, there's no point in sending more pixels than a screen has
+const SIXEL_MAX_DIMENSION: u32 = 1000;
+// Per channel, which makes for a 6x6x6 color cube
+const SIXEL_LEVELS: u32 = 6;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Protocol {
+    Iterm2,
+    Kitty,
+    Sixel,
+}
+
+impl Protocol {
+    pub fn from_name(name: &str) -> Option<Protocol> {
+        match name {
+            "iterm2" => Some(Protocol::Iterm2),
+            "kitty" => Some(Protocol::Kitty),
+            "sixel" => Some(Protocol::Sixel),
+            _ => None,
+        }
+    }
+
+    // Terminals can be asked what they support, but that means reading replies from the TTY; environment is good enough
+    pub fn detect() -> Option<Protocol> {
+        let term: String = env::var("TERM").unwrap_or_default();
+        let term_program: String = env::var("TERM_PROGRAM").unwrap_or_default();
+
+        if env::var_os("KITTY_WINDOW_ID").is_some()
+            || term == "xterm-kitty"
+            || term == "xterm-ghostty"
+        {
+            Some(Protocol::Kitty)
+        } else if ["iTerm.app", "WezTerm"].contains(&term_program.as_str()) {
+            Some(Protocol::Iterm2)
+        } else if term.contains("sixel")
+            || ["foot", "mlterm", "yaft-256color"].contains(&term.as_str())
+        {
+            Some(Protocol::Sixel)
+        } else {
+            None
+        }
+    }
+}
+
+fn decode(data_url: &DataUrl) -> Option<RgbaImage> {
+    image::load_from_memory(data_url.data())
+        .ok()
+        .map(|image| image.to_rgba8())
+}
+
+// Base64 goes out in chunks of at most KITTY_CHUNK_SIZE bytes, all but the last one saying there's more to come
+fn kitty_escape(keys: &str, payload: &[u8]) -> Vec<u8> {
+    let encoded: String = STANDARD.encode(payload);
+    let chunks: Vec<&[u8]> = encoded.as_bytes().chunks(KITTY_CHUNK_SIZE).collect();
+    let mut output: String = String::with_capacity(encoded.len() + chunks.len() * 16);
+
+    for (i, chunk) in chunks.iter().enumerate() {
+        let more: u8 = (i + 1 < chunks.len()) as u8;
+        if i == 0 {
+            let _ = write!(output, "\x1b_Ga=T,{},m={};", keys, more);
+        } else {
+            let _ = write!(output, "\x1b_Gm={};", more);
+        }
+        output.push_str(core::str::from_utf8(chunk).unwrap());
+        output.push_str("\x1b\\");
+    }
+    output.push('\n');
+
+    output.into_bytes()
+}
+
+fn iterm2_escape(data: &[u8]) -> Vec<u8> {
+    format!(
+        "\x1b]1337;File=inline=1;size={};preserveAspectRatio=1:{}\x07\n",
+        data.len(),
+        STANDARD.encode(data)
+    )
+    .into_bytes()
+}
+
+fn sixel_escape(image: &RgbaImage) -> Vec<u8> {
+    let (width, height) = image.dimensions();
+    let scale = |c: u8| (c as u32 * (SIXEL_LEVELS - 1) + 127) / 255;
+    // Fully transparent pixels are left undrawn
+    let pixels: Vec<Option<usize>> = image
+        .pixels()
+        .map(|pixel| {
+            let [r, g, b, a] = pixel.0;
+            if a < 128 {
+                return None;
+            }
+            Some(
+                (scale(r) * SIXEL_LEVELS * SIXEL_LEVELS + scale(g) * SIXEL_LEVELS + scale(b))
+                    as usize,
+            )
+        })
+        .collect();
+    let mut used: Vec<bool> = vec![false; SIXEL_LEVELS.pow(3) as usize];
+    for index in pixels.iter().flatten() {
+        used[*index] = true;
+    }
+    let mut output: String = format!("\x1bPq\"1;1;{};{}", width, height);
+
+    // Only colors which are actually used get defined
+    for (i, _) in used.iter().enumerate().filter(|(_, used)| **used) {
+        let i: u32 = i as u32;
+        let level = |l: u32| l * 100 / (SIXEL_LEVELS - 1);
+        let _ = write!(
+            output,
+            "#{};2;{};{};{}",
+            i,
+            level(i / (SIXEL_LEVELS * SIXEL_LEVELS)),
+            level(i / SIXEL_LEVELS % SIXEL_LEVELS),
+            level(i % SIXEL_LEVELS)
+        );
+    }
+
+    // Every band is six pixels tall, drawn one color at a time
+    for band in (0..height).step_by(6) {
+        let mut rows: Vec<Vec<u8>> = vec![vec![0; width as usize]; SIXEL_LEVELS.pow(3) as usize];
+        for x in 0..width {
+            for bit in 0..6.min(height - band) {
+                if let Some(index) = pixels[((band + bit) * width + x) as usize] {
+                    rows[index][x as usize] |= 1 << bit;
+                }
+            }
+        }
+
+        let mut first: bool = true;
+        for (index, row) in rows.iter().enumerate() {
+            if row.iter().all(|sixel| *sixel == 0) {
+                continue;
+            }
+            if !first {
+                output.push('$');
+            }
+            first = false;
+            let _ = write!(output, "#{}", index);
+
+            // Run-length encoding for repeated sixels
+            let mut x: usize = 0;
+            while x < row.len() {
+                let run: usize = row[x..]
+                    .iter()
+                    .take_while(|sixel| **sixel == row[x])
+                    .count();
+                let c: char = (row[x] + 63) as char;
+                if run > 3 {
+                    let _ = write!(output, "!{}{}", run, c);
+                } else {
+                    output.extend(std::iter::repeat_n(c, run));
+                }
+                x += run;
+            }
+        }
+        output.push('-');
+    }
+    output.push_str("\x1b\\\n");
+
+    output.into_bytes()
+}
+
+// Escape sequences which make the terminal draw the image, None if the data can't be decoded as one
+pub fn render(data_url: &DataUrl, protocol: Protocol) -> Option<Vec<u8>> {
+    match protocol {
+        // iTerm2 decodes images by itself, decoding here only makes sure it's one
+        Protocol::Iterm2 => decode(data_url).map(|_| iterm2_escape(data_url.data())),
+        Protocol::Kitty => {
+            // PNG is the one format kitty decodes on its own, everything else goes as raw RGBA
+            let image: RgbaImage = decode(data_url)?;
+            if data_url.data().starts_with(b"\x89PNG\r\n\x1a\n") {
+                Some(kitty_escape("f=100", data_url.data()))
+            } else {
+                let keys: String = format!("f=32,s={},v={}", image.width(), image.height());
+                Some(kitty_escape(&keys, image.as_raw()))
+            }
+        }
+        Protocol::Sixel => {
+            let mut image: RgbaImage = decode(data_url)?;
+            if image.width() > SIXEL_MAX_DIMENSION || image.height() > SIXEL_MAX_DIMENSION {
+                image = image::DynamicImage::ImageRgba8(image)
+                    .resize(
+                        SIXEL_MAX_DIMENSION,
+                        SIXEL_MAX_DIMENSION,
+                        FilterType::Triangle,
+                    )
+                    .to_rgba8();
+            }
+            Some(sixel_escape(&image))
+        }
+    }
+}
+
+#[cfg(test)]
+mod passing {
+    use image::{Rgba, RgbaImage};
+
+    use super::{iterm2_escape, kitty_escape, sixel_escape, KITTY_CHUNK_SIZE};
+
+    #[test]
+    fn must_fit_payload_of_kitty_chunk_size_into_one_chunk() {
+        let output: Vec<u8> = kitty_escape("f=100", &[0; KITTY_CHUNK_SIZE / 4 * 3]);
+        let output: &str = std::str::from_utf8(&output).unwrap();
+
+        assert!(output.starts_with("\x1b_Ga=T,f=100,m=0;AAAA"));
+        assert_eq!(output.matches("\x1b_G").count(), 1);
+        assert_eq!(
+            output.len(),
+            "\x1b_Ga=T,f=100,m=0;".len() + KITTY_CHUNK_SIZE + 3
+        );
+        assert!(output.ends_with("AAAA\x1b\\\n"));
+    }
+
+    #[test]
+    fn must_split_kitty_payload_into_chunks_of_at_most_kitty_chunk_size() {
+        let output: Vec<u8> = kitty_escape("f=32,s=1,v=1", &[0; KITTY_CHUNK_SIZE / 4 * 3 * 2 + 1]);
+        let output: &str = std::str::from_utf8(&output).unwrap();
+        let chunks: Vec<&str> = output
+            .trim_end_matches('\n')
+            .split_terminator("\x1b\\")
+            .collect();
+
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(
+            chunks[0],
+            format!(
+                "\x1b_Ga=T,f=32,s=1,v=1,m=1;{}",
+                "A".repeat(KITTY_CHUNK_SIZE)
+            )
+        );
+        assert_eq!(
+            chunks[1],
+            format!("\x1b_Gm=1;{}", "A".repeat(KITTY_CHUNK_SIZE))
+        );
+        assert_eq!(chunks[2], "\x1b_Gm=0;AA==");
+    }
+
+    #[test]
+    fn must_tell_iterm2_size_of_data() {
+        assert_eq!(
+            iterm2_escape(b"GIF89a"),
+            b"\x1b]1337;File=inline=1;size=6;preserveAspectRatio=1:R0lGODlh\x07\n"
+        );
+    }
+
+    #[test]
+    fn must_define_used_sixel_colors_and_draw_bands_of_six_rows() {
+        let red: Rgba<u8> = Rgba([255, 0, 0, 255]);
+        let green: Rgba<u8> = Rgba([0, 255, 0, 255]);
+        let blue: Rgba<u8> = Rgba([0, 0, 255, 255]);
+        // Five pixels wide, six rows of red with one transparent pixel, then a row of blue and green
+        let image: RgbaImage = RgbaImage::from_fn(5, 7, |x, y| match (x, y) {
+            (4, 0) => Rgba([255, 0, 0, 0]),
+            (_, 0..=5) => red,
+            (0..=2, _) => blue,
+            _ => green,
+        });
+
+        assert_eq!(
+            std::str::from_utf8(&sixel_escape(&image)).unwrap(),
+            "\x1bPq\"1;1;5;7#5;2;0;0;100#30;2;0;100;0#180;2;100;0;0#180!4~}-#5@@@??$#30???@@-\x1b\\\n"
+        );
+    }
+}
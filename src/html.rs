@@ -0,0 +1,74 @@
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use crate::DataUrl;
+
+const SCRIPT_MEDIA_TYPES: &[&str] = &[
+    "application/ecmascript",
+    "application/javascript",
+    "application/x-javascript",
+    "text/ecmascript",
+    "text/javascript",
+];
+
+#[derive(Clone, Debug, Default)]
+pub struct HtmlOptions {
+    pub integrity: Option<String>, // SRI token, only used for scripts and stylesheets
+    pub alt: Option<String>,       // Alternative text, only used for images
+    pub srcset: Vec<(String, String)>, // Other URLs of the same image along with their descriptors (2x, 480w, etc)
+}
+
+pub(crate) fn escape_attribute(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+impl DataUrl {
+    pub fn to_html(&self, options: &HtmlOptions) -> String {
+        let media_type: &str = self.media_type();
+        let url: String = escape_attribute(&self.to_browser_string());
+        let integrity: String = match &options.integrity {
+            Some(i) => format!(" integrity=\"{}\"", escape_attribute(i)),
+            None => "".to_string(),
+        };
+
+        // Pick the element a browser would load this kind of resource with
+        if SCRIPT_MEDIA_TYPES.contains(&media_type) {
+            format!("<script src=\"{}\"{}></script>", url, integrity)
+        } else if media_type == "text/css" {
+            format!("<link rel=\"stylesheet\" href=\"{}\"{}>", url, integrity)
+        } else if media_type.starts_with("image/") {
+            let mut attributes: String = String::new();
+            if !options.srcset.is_empty() {
+                let candidates: Vec<String> = options
+                    .srcset
+                    .iter()
+                    .map(|(url, descriptor)| format!("{} {}", url, descriptor))
+                    .collect();
+                attributes += &format!(" srcset=\"{}\"", escape_attribute(&candidates.join(", ")));
+            }
+            // Knowing the size upfront lets browsers lay out the page before the image is decoded
+            if let Some((width, height)) = self.image_dimensions() {
+                attributes += &format!(" width=\"{}\" height=\"{}\"", width, height);
+            }
+            if let Some(alt) = &options.alt {
+                attributes += &format!(" alt=\"{}\"", escape_attribute(alt));
+            }
+            format!("<img src=\"{}\"{}>", url, attributes)
+        } else if media_type.starts_with("audio/") {
+            format!("<audio src=\"{}\" controls></audio>", url)
+        } else if media_type.starts_with("video/") {
+            format!("<video src=\"{}\" controls></video>", url)
+        } else {
+            format!(
+                "<object data=\"{}\" type=\"{}\"></object>",
+                url,
+                escape_attribute(media_type)
+            )
+        }
+    }
+}
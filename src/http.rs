@@ -0,0 +1,113 @@
+use ::http::header::{HeaderMap, HeaderValue, CONTENT_ENCODING, CONTENT_LENGTH, CONTENT_TYPE};
+use ::http::Response;
+use bytes::Bytes;
+
+use crate::DataUrl;
+
+fn unquote(value: &str) -> String {
+    if value.len() >= 2 && value.starts_with('"') && value.ends_with('"') {
+        let mut result: String = String::new();
+        let mut chars = value[1..value.len() - 1].chars();
+        while let Some(c) = chars.next() {
+            if c == '\\' {
+                result.extend(chars.next());
+            } else {
+                result.push(c);
+            }
+        }
+        result
+    } else {
+        value.to_string()
+    }
+}
+
+impl DataUrl {
+    // Copies the body, unlike From<Response<Bytes>>
+    pub fn from_response<B: AsRef<[u8]>>(response: &Response<B>) -> DataUrl {
+        DataUrl::from_headers_and_data(
+            response.headers(),
+            Bytes::copy_from_slice(response.body().as_ref()),
+        )
+    }
+
+    fn from_headers_and_data(headers: &HeaderMap, data: Bytes) -> DataUrl {
+        let mut data_url: DataUrl = DataUrl::new();
+        data_url.data = data;
+
+        if let Some(content_type) = headers.get(CONTENT_TYPE).and_then(|v| v.to_str().ok()) {
+            let mut items = content_type.split(';');
+
+            // Invalid media types and charsets are dropped, the same way parse() does it
+            if let Some(media_type) = items.next() {
+                data_url.set_media_type(Some(media_type.trim().to_lowercase()));
+            }
+            for item in items {
+                if let Some((name, value)) = item.split_once('=') {
+                    // Only the first occurence counts, content-encoding comes from its own header
+                    if data_url.parameter(name).is_none()
+                        && !name.trim().eq_ignore_ascii_case("content-encoding")
+                    {
+                        data_url.set_parameter(name, Some(unquote(value.trim())));
+                    }
+                }
+            }
+        }
+
+        // Encodings unknown to ContentEncoding are kept as a parameter, the body staying labeled as compressed
+        if let Some(content_encoding) = headers
+            .get(CONTENT_ENCODING)
+            .and_then(|v| v.to_str().ok())
+            .filter(|v| !v.trim().is_empty())
+        {
+            data_url.set_parameter(
+                "content-encoding",
+                Some(content_encoding.trim().to_string()),
+            );
+        }
+
+        data_url.is_base64_encoded =
+            data_url.is_binary() || data_url.parameter("content-encoding").is_some();
+
+        data_url
+    }
+}
+
+// Takes over the body without copying it
+impl From<Response<Bytes>> for DataUrl {
+    fn from(response: Response<Bytes>) -> Self {
+        let (parts, body) = response.into_parts();
+        DataUrl::from_headers_and_data(&parts.headers, body)
+    }
+}
+
+fn into_response<B>(data_url: &DataUrl, body: B) -> Response<B> {
+    let mut response: Response<B> = Response::new(body);
+    let headers = response.headers_mut();
+
+    // Media types are only loosely validated, those unfit for a header get left out
+    if let Ok(content_type) = HeaderValue::from_str(&data_url.content_type()) {
+        headers.insert(CONTENT_TYPE, content_type);
+    }
+    headers.insert(CONTENT_LENGTH, HeaderValue::from(data_url.data.len()));
+    if let Some(content_encoding) = data_url
+        .parameter("content-encoding")
+        .and_then(|v| HeaderValue::from_str(v).ok())
+    {
+        headers.insert(CONTENT_ENCODING, content_encoding);
+    }
+
+    response
+}
+
+impl From<&DataUrl> for Response<Vec<u8>> {
+    fn from(data_url: &DataUrl) -> Self {
+        into_response(data_url, data_url.data.to_vec())
+    }
+}
+
+// Shares the payload with the DataUrl instead of copying it
+impl From<&DataUrl> for Response<Bytes> {
+    fn from(data_url: &DataUrl) -> Self {
+        into_response(data_url, data_url.data.clone())
+    }
+}
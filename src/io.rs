@@ -0,0 +1,34 @@
+use std::fs;
+use std::io::{self, Read, Write};
+use std::path::Path;
+
+use crate::DataUrl;
+
+impl DataUrl {
+    pub fn from_file<P: AsRef<Path>>(path: P) -> io::Result<DataUrl> {
+        Ok(DataUrl::from_sniffed_bytes(fs::read(path)?))
+    }
+
+    pub fn from_reader<R: Read>(mut reader: R) -> io::Result<DataUrl> {
+        let mut data: Vec<u8> = Vec::new();
+        reader.read_to_end(&mut data)?;
+
+        Ok(DataUrl::from_sniffed_bytes(data))
+    }
+
+    pub fn parse_reader<R: Read>(mut reader: R) -> io::Result<DataUrl> {
+        let mut input: Vec<u8> = Vec::new();
+        reader.read_to_end(&mut input)?;
+
+        DataUrl::parse(&String::from_utf8_lossy(&input))
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+    }
+
+    pub fn write_to<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        writer.write_all(self.to_string().as_bytes())
+    }
+
+    pub fn write_data_to<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        writer.write_all(&self.data)
+    }
+}
@@ -1,12 +1,79 @@
-use base64::{engine::general_purpose::URL_SAFE, Engine as _};
-use encoding_rs::Encoding;
-use percent_encoding::{percent_decode_str, percent_encode, utf8_percent_encode, NON_ALPHANUMERIC};
-use std::fmt;
-use url::Url;
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
 
-const DEFAULT_MEDIA_TYPE: &'static str = "text/plain";
-const DEFAULT_CHARSET: &'static str = "US-ASCII";
-const TEXTUAL_MEDIA_TYPES: &'static [&str] = &[
+use alloc::borrow::Cow;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+use encoding_rs::Encoding;
+use percent_encoding::{
+    percent_decode_str, utf8_percent_encode, AsciiSet, CONTROLS, NON_ALPHANUMERIC,
+};
+
+use crate::url::Url;
+
+mod builder;
+mod canonical;
+#[cfg(feature = "capi")]
+pub mod capi;
+mod codec;
+mod compression;
+mod css;
+#[cfg(feature = "digest")]
+mod digest;
+mod dimensions;
+#[cfg(feature = "futures")]
+pub mod futures;
+mod html;
+#[cfg(feature = "http")]
+mod http;
+#[cfg(feature = "std")]
+mod io;
+mod lossless;
+#[cfg(feature = "mime")]
+mod mime;
+mod multipart;
+mod options;
+#[cfg(feature = "python")]
+pub mod python;
+mod reencode;
+mod rfc2397;
+#[cfg(feature = "std")]
+mod sniff;
+#[cfg(any(feature = "futures", feature = "tokio"))]
+mod stream;
+#[cfg(feature = "tokio")]
+pub mod tokio;
+mod url;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+mod whatwg;
+
+pub use builder::{DataUrlBuildError, DataUrlBuilder};
+pub use bytes::Bytes;
+pub use compression::ContentEncoding;
+pub use css::FontFaceOptions;
+#[cfg(feature = "digest")]
+pub use digest::Algorithm;
+pub use html::HtmlOptions;
+pub use multipart::{html_to_multipart, multipart_to_html, MultipartError};
+pub use options::{ParseMode, ParseOptions, StringOptions};
+pub use reencode::{ImageFormat, ImageOptions};
+
+const DEFAULT_MEDIA_TYPE: &str = "text/plain";
+const DEFAULT_CHARSET: &str = "US-ASCII";
+// Characters which would otherwise end a parameter value (or the whole meta data) prematurely
+const PARAMETER_VALUE: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'#')
+    .add(b'%')
+    .add(b',')
+    .add(b';')
+    .add(b'?');
+const TEXTUAL_MEDIA_TYPES: &[&str] = &[
     "application/atom+xml",
     "application/dart",
     "application/ecmascript",
@@ -29,65 +96,136 @@ const TEXTUAL_MEDIA_TYPES: &'static [&str] = &[
     "model/x3d+xml",
 ];
 
-// TODO: add support for other optional parameters besides charset (filename, etc)
+// Name-value pairs, names being lowercase
+type Parameters = Vec<(String, String)>;
+// Media type, charset, content encoding, other parameters and the base64 flag
+type MetaData = (
+    Option<String>,
+    Option<String>,
+    Option<ContentEncoding>,
+    Parameters,
+    bool,
+);
+
+#[derive(Clone)]
 pub struct DataUrl {
-    media_type: Option<String>, // Media type
-    charset: Option<String>,    // US-ASCII is default, according to the spec
-    is_base64_encoded: bool,    // Indicates if it's a base64-encoded data URL
-    data: Vec<u8>,              // Data, bytes, UTF-8 if text
-    fragment: Option<String>,   // #something-at-the-end, None by default
+    media_type: Option<String>,                // Media type
+    charset: Option<String>,                   // US-ASCII is default, according to the spec
+    content_encoding: Option<ContentEncoding>, // Compression applied to data, if any
+    parameters: Parameters,                    // Other parameters (filename, etc), in order
+    is_base64_encoded: bool,                   // Indicates if it's a base64-encoded data URL
+    data: Bytes,                               // Data, bytes, UTF-8 if text; shared between clones
+    fragment: Option<String>,                  // #something-at-the-end, None by default
+    original: Option<lossless::Original>,      // Verbatim input, set by parse_lossless()
 }
 
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum DataUrlParseError {
     UrlParseError,
     MalformedDataUrlError,
     Base64DecodeError,
+    LimitExceeded,
+    InvalidMediaType,
+    InvalidParameter,
+    InvalidCharacter,
+    DecompressionError,
 }
 
-impl fmt::Debug for DataUrlParseError {
+impl fmt::Display for DataUrlParseError {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
-        fmt.debug_struct("DataUrlParseError").finish()
+        fmt.write_str(match self {
+            DataUrlParseError::UrlParseError => "unable to parse URL",
+            DataUrlParseError::MalformedDataUrlError => "malformed data URL",
+            DataUrlParseError::Base64DecodeError => "unable to decode base64",
+            DataUrlParseError::LimitExceeded => "limit exceeded",
+            DataUrlParseError::InvalidMediaType => "invalid media type",
+            DataUrlParseError::InvalidParameter => "invalid parameter",
+            DataUrlParseError::InvalidCharacter => "invalid character",
+            DataUrlParseError::DecompressionError => "unable to decompress data",
+        })
     }
 }
 
-pub(crate) fn parse_data_url_meta_data(
-    meta_data_string: String,
-) -> (Option<String>, Option<String>, bool) {
+#[cfg(feature = "std")]
+impl std::error::Error for DataUrlParseError {}
+
+pub(crate) fn parse_data_url_meta_data(meta_data_string: String) -> MetaData {
     let mut media_type: Option<String> = None;
     let mut charset: Option<String> = None;
+    let mut content_encoding: Option<ContentEncoding> = None;
+    let mut parameters: Parameters = vec![];
     let mut is_base64_encoded: bool = false;
 
     // Parse meta data
     let content_type_items: Vec<&str> = meta_data_string.split(';').collect();
-    let mut i: i8 = 0;
-    for item in &content_type_items {
+    for (i, item) in content_type_items.iter().enumerate() {
         // Media type has to always come first in data URLs
         if i == 0 {
-            if item.trim().len() > 0 && validate_media_type(item) {
-                media_type = Some(item.trim().to_lowercase().to_string());
+            if !item.trim().is_empty() && validate_media_type(item) {
+                media_type = Some(item.trim().to_lowercase());
             }
-        } else {
-            if !is_base64_encoded && item.trim().to_lowercase().starts_with("charset=") {
-                // only the first occurence of charset counts
-                if charset.is_none() {
-                    if let Some(e) = Encoding::for_label_no_replacement((&item[8..]).as_bytes()) {
-                        charset = Some(e.name().to_string());
-                    }
+        } else if !is_base64_encoded && item.trim().to_lowercase().starts_with("charset=") {
+            // only the first occurence of charset counts
+            if charset.is_none() {
+                if let Some(e) = Encoding::for_label_no_replacement(&item.as_bytes()[8..]) {
+                    charset = Some(e.name().to_string());
                 }
-            } else if item.trim().eq_ignore_ascii_case("base64") {
-                is_base64_encoded = true;
+            }
+        } else if !is_base64_encoded && item.trim().to_lowercase().starts_with("content-encoding=")
+        {
+            // only the first occurence of content-encoding counts as well,
+            // values this crate can't decompress being kept as an ordinary parameter
+            if content_encoding.is_none()
+                && !parameters.iter().any(|(n, _)| n == "content-encoding")
+            {
+                let value: &str = item.trim()[17..].trim();
+                match ContentEncoding::from_name(value) {
+                    Some(ce) => content_encoding = Some(ce),
+                    None if !value.is_empty() => parameters.push((
+                        "content-encoding".to_string(),
+                        percent_decode_str(value).decode_utf8_lossy().to_string(),
+                    )),
+                    None => {}
+                }
+            }
+        } else if item.trim().eq_ignore_ascii_case("base64") {
+            is_base64_encoded = true;
+        } else if let (false, Some((name, value))) = (is_base64_encoded, item.split_once('=')) {
+            // Same as with charset, the first occurence of any other parameter counts
+            let name: String = name.trim().to_lowercase();
+            if !name.is_empty() && !parameters.iter().any(|(n, _)| *n == name) {
+                let value: String = percent_decode_str(value.trim())
+                    .decode_utf8_lossy()
+                    .to_string();
+                parameters.push((name, value));
             }
         }
-
-        i += 1;
     }
 
-    (media_type, charset, is_base64_encoded)
+    (
+        media_type,
+        charset,
+        content_encoding,
+        parameters,
+        is_base64_encoded,
+    )
+}
+
+// Anything else would either get percent-encoded or be mistaken for a delimiter
+fn is_meta_data_safe(s: &str) -> bool {
+    s.chars()
+        .all(|c| (c == ' ' || c.is_ascii_graphic()) && !matches!(c, ',' | ';' | '#' | '?'))
 }
 
 pub(crate) fn validate_media_type(media_type: &str) -> bool {
     // Must contain one slash
-    media_type.split('/').collect::<Vec<&str>>().len() == 2
+    media_type.split('/').collect::<Vec<&str>>().len() == 2 && is_meta_data_safe(media_type)
+}
+
+impl Default for DataUrl {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl DataUrl {
@@ -95,59 +233,173 @@ impl DataUrl {
         DataUrl {
             media_type: None,
             charset: None,
+            content_encoding: None,
+            parameters: vec![],
             is_base64_encoded: false,
-            data: [].to_vec(),
+            data: Bytes::new(),
             fragment: None,
+            original: None,
+        }
+    }
+
+    pub fn builder() -> DataUrlBuilder {
+        DataUrlBuilder::new()
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> DataUrl {
+        DataUrlBuilder::new()
+            .data(Bytes::copy_from_slice(bytes))
+            .build()
+    }
+
+    pub fn from_text(text: &str) -> DataUrl {
+        let mut data_url = DataUrlBuilder::new()
+            .data(Bytes::copy_from_slice(text.as_bytes()))
+            .build();
+
+        // US-ASCII is the default, anything beyond it requires an explicit charset
+        if !text.is_ascii() {
+            data_url.charset = Some("UTF-8".to_string());
+        }
+
+        data_url
+    }
+
+    // What the payload's magic bytes say it is, whatever media type it's labeled with
+    #[cfg(feature = "std")]
+    pub fn sniffed_media_type(&self) -> Option<&'static str> {
+        sniff::sniff_media_type(&self.data)
+    }
+
+    #[cfg(feature = "std")]
+    pub(crate) fn from_sniffed_bytes(data: Vec<u8>) -> DataUrl {
+        match sniff::sniff_media_type(&data) {
+            Some(media_type) => {
+                let mut data_url = DataUrlBuilder::new().data(data).build();
+                data_url.media_type = Some(media_type.to_string());
+                if data_url.is_binary() {
+                    data_url.is_base64_encoded = true;
+                } else if !data_url.data.is_ascii() && core::str::from_utf8(&data_url.data).is_ok()
+                {
+                    data_url.charset = Some("UTF-8".to_string());
+                }
+                data_url
+            }
+            None => match String::from_utf8(data) {
+                Ok(text) => DataUrl::from_text(&text),
+                Err(err) => {
+                    let mut data_url = DataUrlBuilder::new().data(err.into_bytes()).build();
+                    data_url.media_type = Some("application/octet-stream".to_string());
+                    data_url.is_base64_encoded = true;
+                    data_url
+                }
+            },
         }
     }
 
     // TODO: rename to from_string/from_str/from — look for how it's done for String and similar
     pub fn parse(input_str: &str) -> Result<Self, DataUrlParseError> {
+        DataUrl::parse_with_options(input_str, &ParseOptions::default())
+    }
+
+    pub fn parse_with_options(
+        input_str: &str,
+        options: &ParseOptions,
+    ) -> Result<Self, DataUrlParseError> {
+        options.check_input(input_str)?;
+        if options.mode == ParseMode::Rfc2397Strict {
+            rfc2397::validate(input_str)?;
+        }
+
         match Url::parse(input_str) {
             Ok(url) => {
-                let path: String = url.path().to_string();
+                let path: &str = url.path();
                 if let Some(comma_offset) = path.find(',') {
                     let fragment: Option<&str> = url.fragment();
 
                     // Parse meta data
                     let meta_data_string = String::from(&path[..comma_offset]);
-                    let (media_type, charset, is_base64_encoded) =
-                        parse_data_url_meta_data(meta_data_string);
+                    options.check_meta_data(&meta_data_string)?;
+                    let (media_type, charset, content_encoding, parameters, is_base64_encoded) =
+                        match options.mode {
+                            ParseMode::Whatwg => whatwg::parse_meta_data(&meta_data_string),
+                            _ => parse_data_url_meta_data(meta_data_string),
+                        };
+
+                    // Payloads which can't fit within the limit get rejected before being decoded,
+                    // the extra pass over them only being made if there's a limit
+                    let raw_data: &str = &path[comma_offset + 1..];
+                    let is_limited: bool = options.max_decoded_len.is_some();
+                    if is_limited && !is_base64_encoded {
+                        options.check_decoded_len(
+                            codec::percent_decoded_len_min(raw_data)
+                                + url
+                                    .query()
+                                    .map_or(0, |query| 1 + codec::percent_decoded_len_min(query)),
+                        )?;
+                    }
 
-                    // Parse raw data into vector of bytes
-                    let mut d: Vec<u8> = percent_decode_str(&path[comma_offset + 1..]).collect();
+                    // Parse raw data into vector of bytes, borrowing it from the input if possible
+                    let mut d: Cow<[u8]> = codec::percent_decode(raw_data);
                     if let Some(query) = url.query() {
-                        d.push("?".as_bytes()[0]);
-                        d.append(&mut percent_decode_str(&query).collect());
+                        let d: &mut Vec<u8> = d.to_mut();
+                        d.push(b'?');
+                        d.extend_from_slice(&codec::percent_decode(query));
+                    }
+                    if is_limited && is_base64_encoded {
+                        options.check_decoded_len(codec::base64_decoded_len(&d))?;
                     }
                     let mut unable_to_decode_base64: bool = false;
-                    let blob: Vec<u8> = if is_base64_encoded {
-                        match URL_SAFE.decode(&d) {
-                            Ok(decoded) => decoded,
-                            Err(_) => {
+                    let blob: Vec<u8> = if is_base64_encoded && options.mode == ParseMode::Whatwg {
+                        match whatwg::forgiving_base64_decode(&d) {
+                            Some(decoded) => decoded,
+                            None => {
                                 unable_to_decode_base64 = true;
-                                [].to_vec()
+                                vec![]
+                            }
+                        }
+                    } else if is_base64_encoded {
+                        // Line breaks are gone by now, but spaces (and percent-encoded whitespace) aren't
+                        if options.ignore_base64_whitespace
+                            && d.iter().any(|b| b.is_ascii_whitespace())
+                        {
+                            d.to_mut().retain(|b| !b.is_ascii_whitespace());
+                        }
+                        match codec::base64_decode(&d) {
+                            Some(decoded) => decoded,
+                            None => {
+                                unable_to_decode_base64 = true;
+                                vec![]
                             }
                         }
                     } else {
-                        d
+                        d.into_owned()
                     };
 
                     if unable_to_decode_base64 {
                         return Err(DataUrlParseError::Base64DecodeError);
                     }
+                    options.check_decoded_len(blob.len())?;
+
+                    #[allow(unused_mut)]
+                    let mut data_url = DataUrl {
+                        media_type,
+                        charset,
+                        content_encoding,
+                        parameters,
+                        is_base64_encoded,
+                        data: Bytes::from(blob),
+                        fragment: fragment
+                            .map(|f| percent_decode_str(f).decode_utf8_lossy().to_string()),
+                        original: None,
+                    };
+
+                    #[cfg(feature = "compression")]
+                    if options.decompress {
+                        data_url.decompress_with_options(options)?;
+                    }
 
-                    Ok(DataUrl {
-                        media_type: media_type,
-                        charset: charset,
-                        is_base64_encoded: is_base64_encoded,
-                        data: blob,
-                        fragment: if let Some(f) = fragment {
-                            Some(f.to_string())
-                        } else {
-                            None
-                        },
-                    })
+                    Ok(data_url)
                 } else {
                     Err(DataUrlParseError::MalformedDataUrlError)
                 }
@@ -161,7 +413,7 @@ impl DataUrl {
             return false;
         }
 
-        let current_media_type: &str = &self.media_type.as_ref().unwrap();
+        let current_media_type: &str = self.media_type.as_ref().unwrap();
         let is_textual: bool = if current_media_type.split('/').collect::<Vec<&str>>()[0]
             .eq_ignore_ascii_case("text")
         {
@@ -169,8 +421,7 @@ impl DataUrl {
         } else {
             TEXTUAL_MEDIA_TYPES
                 .iter()
-                .find(|mt| current_media_type.eq_ignore_ascii_case(mt))
-                .is_some()
+                .any(|mt| current_media_type.eq_ignore_ascii_case(mt))
         };
 
         !is_textual
@@ -185,17 +436,13 @@ impl DataUrl {
     }
 
     pub fn media_type_no_default(&self) -> Option<String> {
-        if let Some(mt) = &self.media_type {
-            Some(mt.to_string())
-        } else {
-            None
-        }
+        self.media_type.clone()
     }
 
     pub fn set_media_type(&mut self, new_media_type: Option<String>) -> bool {
-        if let Some(mt) = new_media_type {
-            if mt.trim().len() > 0 && validate_media_type(&mt) {
-                self.media_type = Some(mt.to_string());
+        let success: bool = if let Some(mt) = new_media_type {
+            if !mt.trim().is_empty() && validate_media_type(&mt) {
+                self.media_type = Some(mt.trim().to_lowercase());
                 true
             } else {
                 // Empty media type makes it fall back to default (text/plain)
@@ -205,7 +452,13 @@ impl DataUrl {
         } else {
             self.media_type = None;
             true
+        };
+
+        if let Some(original) = &mut self.original {
+            original.set_media_type(self.media_type.as_deref());
         }
+
+        success
     }
 
     pub fn charset(&self) -> &str {
@@ -217,15 +470,11 @@ impl DataUrl {
     }
 
     pub fn charset_no_default(&self) -> Option<String> {
-        if let Some(c) = &self.charset {
-            Some(c.to_string())
-        } else {
-            None
-        }
+        self.charset.clone()
     }
 
     pub fn set_charset(&mut self, new_charset: Option<String>) -> bool {
-        if let Some(nc) = new_charset {
+        let success: bool = if let Some(nc) = new_charset {
             // Validate the input
             if let Some(e) = Encoding::for_label_no_replacement(nc.as_bytes()) {
                 self.charset = Some(e.name().to_string());
@@ -239,6 +488,107 @@ impl DataUrl {
             // Unset
             self.charset = None;
             true
+        };
+
+        if let Some(original) = &mut self.original {
+            // encoding_rs calls US-ASCII windows-1252, the original label reads better
+            let label: Option<&str> = self.charset.as_deref().map(|c| {
+                if c == "windows-1252" {
+                    DEFAULT_CHARSET
+                } else {
+                    c
+                }
+            });
+            original.set_parameter("charset", label);
+        }
+
+        success
+    }
+
+    pub fn content_encoding(&self) -> Option<ContentEncoding> {
+        self.content_encoding
+    }
+
+    pub fn set_content_encoding(&mut self, new_content_encoding: Option<ContentEncoding>) {
+        self.content_encoding = new_content_encoding;
+        // Replaces any content-encoding unknown to ContentEncoding as well
+        self.parameters.retain(|(n, _)| n != "content-encoding");
+
+        if let Some(original) = &mut self.original {
+            original.set_parameter("content-encoding", new_content_encoding.map(|ce| ce.name()));
+        }
+    }
+
+    pub fn parameter(&self, name: &str) -> Option<&str> {
+        match name.trim().to_lowercase().as_str() {
+            "charset" => self.charset.as_deref(),
+            "content-encoding" => match self.content_encoding {
+                Some(ce) => Some(ce.name()),
+                None => self
+                    .parameters
+                    .iter()
+                    .find(|(n, _)| n == "content-encoding")
+                    .map(|(_, v)| v.as_str()),
+            },
+            name => self
+                .parameters
+                .iter()
+                .find(|(n, _)| n == name)
+                .map(|(_, v)| v.as_str()),
+        }
+    }
+
+    // Parameters other than charset and content-encoding, those have their own getters;
+    // content-encoding not known to ContentEncoding is an ordinary parameter though
+    pub fn parameters(&self) -> &[(String, String)] {
+        &self.parameters
+    }
+
+    pub fn set_parameter(&mut self, name: &str, new_value: Option<String>) -> bool {
+        let name: String = name.trim().to_lowercase();
+
+        match name.as_str() {
+            "charset" => self.set_charset(new_value),
+            "content-encoding" => match new_value.as_deref().map(ContentEncoding::from_name) {
+                // Unknown values are kept as they are, the payload staying labeled as compressed
+                Some(None) => {
+                    let value: String = new_value.unwrap();
+                    self.set_content_encoding(None);
+                    if let Some(original) = &mut self.original {
+                        let encoded: String =
+                            utf8_percent_encode(&value, PARAMETER_VALUE).to_string();
+                        original.set_parameter(&name, Some(&encoded));
+                    }
+                    self.parameters.push((name, value));
+                    true
+                }
+                content_encoding => {
+                    self.set_content_encoding(content_encoding.flatten());
+                    true
+                }
+            },
+            // Base64 is a flag rather than a parameter, and nameless parameters can't be told apart
+            "" | "base64" => false,
+            _ if name.contains('=') || !is_meta_data_safe(&name) => false,
+            _ => {
+                let position: Option<usize> = self.parameters.iter().position(|(n, _)| *n == name);
+                match (position, &new_value) {
+                    (Some(p), Some(v)) => self.parameters[p].1 = v.clone(),
+                    (Some(p), None) => {
+                        self.parameters.remove(p);
+                    }
+                    (None, Some(v)) => self.parameters.push((name.clone(), v.clone())),
+                    (None, None) => {}
+                }
+
+                if let Some(original) = &mut self.original {
+                    let value: Option<String> =
+                        new_value.map(|v| utf8_percent_encode(&v, PARAMETER_VALUE).to_string());
+                    original.set_parameter(&name, value.as_deref());
+                }
+
+                true
+            }
         }
     }
 
@@ -251,6 +601,10 @@ impl DataUrl {
 
     pub fn set_is_base64_encoded(&mut self, new_is_base64_encoded: bool) {
         self.is_base64_encoded = new_is_base64_encoded;
+
+        if let Some(original) = &mut self.original {
+            original.set_is_base64_encoded(new_is_base64_encoded);
+        }
     }
 
     pub fn data(&self) -> &[u8] {
@@ -292,87 +646,203 @@ impl DataUrl {
     */
 
     pub fn set_data(&mut self, new_data: &[u8]) {
-        self.data = new_data.to_vec();
+        self.set_data_owned(Bytes::copy_from_slice(new_data));
     }
 
-    pub fn fragment(&self) -> Option<String> {
-        if let Some(f) = &self.fragment {
-            Some(f.to_string())
-        } else {
-            None
+    // Takes over a Vec<u8> (or Bytes) without copying it
+    pub fn set_data_owned<D: Into<Bytes>>(&mut self, new_data: D) {
+        self.data = new_data.into();
+
+        if let Some(original) = &mut self.original {
+            original.set_data();
         }
     }
 
+    // Cheap to call, the payload itself doesn't get copied
+    pub fn data_bytes(&self) -> Bytes {
+        self.data.clone()
+    }
+
+    // Copies the payload only if it's shared with some other DataUrl or Bytes
+    pub fn into_data(self) -> Vec<u8> {
+        Vec::from(self.data)
+    }
+
+    pub fn fragment(&self) -> Option<String> {
+        self.fragment.clone()
+    }
+
     pub fn set_fragment(&mut self, new_fragment: Option<String>) {
         self.fragment = new_fragment;
+
+        if let Some(original) = &mut self.original {
+            original.set_fragment();
+        }
     }
+}
 
-    // TODO: rename it to as_str/to_str, make it return a &str instead of String
-    // TODO: make it an Option(Result?), throw error in case is_base64_encoded=false, and charset!=default|utf8
-    pub fn to_string(&self) -> String {
-        let mut result: String = String::from("data:");
+impl DataUrl {
+    // Canonical meta data lists parameters sorted by name, for equivalent URLs to serialize the same way
+    pub(crate) fn meta_data_to_string(&self, canonical: bool) -> String {
+        let mut result: String = String::new();
 
         if let Some(mt) = &self.media_type {
-            result += &mt;
+            result += mt;
         }
 
         if let Some(c) = &self.charset {
             // windows-1252 is another name for US-ASCII, the default charset for data URLs
             if c != "windows-1252" {
                 result += ";charset=";
-                result += &c;
+                result += c;
             }
         }
 
+        result += &self.parameters_to_string(canonical);
+
+        if let Some(ce) = &self.content_encoding {
+            result += ";content-encoding=";
+            result += ce.name();
+        }
+
+        if self.is_base64_encoded {
+            result += ";base64";
+        }
+
+        result
+    }
+
+    pub(crate) fn parameters_to_string(&self, canonical: bool) -> String {
+        let mut result: String = String::new();
+        let mut parameters: Vec<&(String, String)> = self.parameters.iter().collect();
+        if canonical {
+            parameters.sort_by_cached_key(|(name, _)| name.to_lowercase());
+        }
+
+        for (name, value) in parameters {
+            result += ";";
+            result += name;
+            result += "=";
+            result += &utf8_percent_encode(value, PARAMETER_VALUE).to_string();
+        }
+
+        result
+    }
+
+    // Same as what browsers report for data URLs fetched via fetch() or XHR
+    #[cfg(any(feature = "http", feature = "mime"))]
+    pub(crate) fn content_type(&self) -> String {
+        let mut result: String = self.media_type().to_string();
+
+        if self.media_type.is_none() || self.charset.is_some() {
+            result += ";charset=";
+            result += match self.charset.as_deref() {
+                Some("windows-1252") | None => DEFAULT_CHARSET,
+                Some(charset) => charset,
+            };
+        }
+
+        // Content-encoding has a header of its own
+        for (name, value) in self
+            .parameters
+            .iter()
+            .filter(|(n, _)| n != "content-encoding")
         {
-            if self.is_base64_encoded {
-                result += ";base64";
+            result += ";";
+            result += name;
+            result += "=";
+            // Anything but a token has to be a quoted string in HTTP headers
+            if !value.is_empty()
+                && value
+                    .bytes()
+                    .all(|b| b.is_ascii_alphanumeric() || b"!#$%&'*+-.^_`|~".contains(&b))
+            {
+                result += value;
+            } else {
+                result += "\"";
+                result += &value.replace('\\', "\\\\").replace('"', "\\\"");
+                result += "\"";
             }
-            result += ",";
+        }
 
-            if self.data.len() > 0 {
-                if self.is_binary() {
-                    // Just encode as base64 or URI if data is binary
-                    if self.is_base64_encoded {
-                        result += &URL_SAFE.encode(&self.data);
-                    } else {
-                        result += &percent_encode(&self.data, NON_ALPHANUMERIC).to_string();
-                    }
-                } else {
-                    // Charset only matters for textual data
-                    let data_as_utf8_string: String =
-                        String::from_utf8_lossy(&self.data).to_string();
-                    let fallback_charset: String = if data_as_utf8_string.is_ascii() {
-                        DEFAULT_CHARSET.to_string()
-                    } else {
-                        "UTF-8".to_string()
-                    };
+        result
+    }
 
-                    if let Some(encoding) = Encoding::for_label_no_replacement(
-                        self.charset
-                            .as_ref()
-                            .unwrap_or(&fallback_charset)
-                            .as_bytes(),
-                    ) {
-                        let (encoded, _, _) = encoding.encode(&data_as_utf8_string);
-
-                        if self.is_base64_encoded {
-                            result += &URL_SAFE.encode(&encoded.to_vec());
-                        } else {
-                            result +=
-                                &percent_encode(&encoded.to_vec(), NON_ALPHANUMERIC).to_string();
-                        }
-                    }
-                }
-            }
+    pub(crate) fn data_to_string(&self) -> String {
+        // Data is already in the declared charset, so it goes out byte for byte
+        if self.is_base64_encoded {
+            codec::base64_encode(&self.data)
+        } else {
+            codec::percent_encode(&self.data)
+        }
+    }
+
+    // Verbatim input of losslessly parsed URLs may use the URL-safe base64 alphabet, which browsers reject
+    pub(crate) fn to_browser_string(&self) -> String {
+        match &self.original {
+            Some(original) => original.to_browser_string(self),
+            None => self.to_string(),
+        }
+    }
+
+    // Same as to_string(), unless told to wrap a base64 payload; that takes rebuilding the URL, even if parsed losslessly
+    pub fn to_string_with_options(&self, options: &StringOptions) -> String {
+        let width: usize = match options.wrap {
+            Some(width) if self.is_base64_encoded => width.max(1),
+            _ => return self.to_string(),
+        };
+        let mut result: String = String::from("data:");
+        result += &self.meta_data_to_string(false);
+        result += ",";
+
+        // The first line gets filled up to the same width as the rest
+        let payload: String = self.data_to_string();
+        let first_line_len: usize = width.saturating_sub(result.len()).min(payload.len());
+        result += &payload[..first_line_len];
+        if first_line_len < payload.len() {
+            result += "\r\n";
+            result += codec::wrap_lines(&payload[first_line_len..], width).trim_end_matches("\r\n");
         }
 
-        if let Some(f) = &self.fragment {
+        if let Some(f) = self.fragment_to_string() {
             result += "#";
-            // TODO: need to deal with encoding here as well
-            result += &utf8_percent_encode(f, NON_ALPHANUMERIC).to_string();
+            result += &f;
         }
 
         result
     }
+
+    pub(crate) fn fragment_to_string(&self) -> Option<String> {
+        // TODO: need to deal with encoding here as well
+        self.fragment
+            .as_ref()
+            .map(|f| utf8_percent_encode(f, NON_ALPHANUMERIC).to_string())
+    }
+}
+
+// TODO: rename it to as_str/to_str, make it return a &str instead of String
+// TODO: make it an Option(Result?), throw error in case is_base64_encoded=false, and charset!=default|utf8
+impl fmt::Display for DataUrl {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(original) = &self.original {
+            return fmt.write_str(&original.to_string(self));
+        }
+
+        // The payload gets encoded straight into the formatter, never existing as a String of its own
+        fmt.write_str("data:")?;
+        fmt.write_str(&self.meta_data_to_string(false))?;
+        fmt.write_str(",")?;
+        if self.is_base64_encoded {
+            codec::write_base64(fmt, &self.data)?;
+        } else {
+            codec::write_percent_encoded(fmt, &self.data)?;
+        }
+
+        if let Some(f) = self.fragment_to_string() {
+            fmt.write_str("#")?;
+            fmt.write_str(&f)?;
+        }
+
+        Ok(())
+    }
 }
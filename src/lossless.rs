@@ -0,0 +1,168 @@
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use crate::{DataUrl, DataUrlParseError, ParseOptions};
+
+// Pieces of the input that parse_lossless() saw, kept verbatim until the corresponding property changes
+#[derive(Clone)]
+pub(crate) struct Original {
+    scheme: String,           // Everything up to and including the colon
+    meta_data: Vec<String>,   // Semicolon-separated items
+    data: Option<String>,     // Raw payload, None once data gets changed
+    fragment: Option<String>, // Raw fragment without the number sign
+    fragment_changed: bool,   // Indicates that the fragment needs to be regenerated
+}
+
+impl Original {
+    pub(crate) fn set_media_type(&mut self, new_media_type: Option<&str>) {
+        self.meta_data[0] = new_media_type.unwrap_or("").to_string();
+    }
+
+    pub(crate) fn set_parameter(&mut self, name: &str, value: Option<&str>) {
+        let prefix: String = format!("{}=", name);
+        let position: Option<usize> = self
+            .meta_data
+            .iter()
+            .skip(1)
+            .position(|item| item.trim().to_lowercase().starts_with(&prefix))
+            .map(|p| p + 1);
+
+        match (position, value) {
+            (Some(p), Some(v)) => self.meta_data[p] = format!("{}={}", name, v),
+            (Some(p), None) => {
+                self.meta_data.remove(p);
+            }
+            (None, Some(v)) => {
+                // New parameters go in front of the base64 marker, which has to come last
+                let base64_position: Option<usize> = self
+                    .meta_data
+                    .iter()
+                    .skip(1)
+                    .position(|item| item.trim().eq_ignore_ascii_case("base64"))
+                    .map(|p| p + 1);
+                self.meta_data.insert(
+                    base64_position.unwrap_or(self.meta_data.len()),
+                    format!("{}={}", name, v),
+                );
+            }
+            (None, None) => {}
+        }
+    }
+
+    pub(crate) fn set_is_base64_encoded(&mut self, new_is_base64_encoded: bool) {
+        let is_base64_item = |item: &String| item.trim().eq_ignore_ascii_case("base64");
+        let was_base64_encoded: bool = self.meta_data.iter().skip(1).any(is_base64_item);
+
+        if was_base64_encoded == new_is_base64_encoded {
+            return;
+        }
+
+        if new_is_base64_encoded {
+            self.meta_data.push("base64".to_string());
+        } else {
+            // The first item is the media type, which is never treated as the base64 marker
+            let media_type: String = self.meta_data.remove(0);
+            self.meta_data.retain(|item| !is_base64_item(item));
+            self.meta_data.insert(0, media_type);
+        }
+
+        // Payload has to be encoded the other way now
+        self.data = None;
+    }
+
+    pub(crate) fn set_data(&mut self) {
+        self.data = None;
+    }
+
+    pub(crate) fn set_fragment(&mut self) {
+        self.fragment_changed = true;
+    }
+
+    pub(crate) fn to_string(&self, data_url: &DataUrl) -> String {
+        self.serialize(data_url, false)
+    }
+
+    // Everything but a base64 payload stays verbatim, the payload gets re-encoded using the standard alphabet
+    pub(crate) fn to_browser_string(&self, data_url: &DataUrl) -> String {
+        self.serialize(data_url, data_url.is_base64_encoded())
+    }
+
+    fn serialize(&self, data_url: &DataUrl, reencode_data: bool) -> String {
+        let mut result: String = self.scheme.clone();
+
+        result += &self.meta_data.join(";");
+        result += ",";
+
+        match &self.data {
+            Some(d) if !reencode_data => result += d,
+            _ => result += &data_url.data_to_string(),
+        }
+
+        let fragment: Option<String> = if self.fragment_changed {
+            data_url.fragment_to_string()
+        } else {
+            self.fragment.clone()
+        };
+        if let Some(f) = fragment {
+            result += "#";
+            result += &f;
+        }
+
+        result
+    }
+}
+
+impl DataUrl {
+    pub fn parse_lossless(input_str: &str) -> Result<Self, DataUrlParseError> {
+        DataUrl::parse_lossless_with_options(input_str, &ParseOptions::default())
+    }
+
+    pub fn parse_lossless_with_options(
+        input_str: &str,
+        options: &ParseOptions,
+    ) -> Result<Self, DataUrlParseError> {
+        // Decompressing has to wait until there's an original for it to update
+        #[cfg(feature = "compression")]
+        let parse_options: ParseOptions = ParseOptions {
+            decompress: false,
+            ..options.clone()
+        };
+        #[cfg(not(feature = "compression"))]
+        let parse_options: ParseOptions = options.clone();
+        let mut data_url: DataUrl = DataUrl::parse_with_options(input_str, &parse_options)?;
+
+        // Split the input the same way parse() does, but without normalizing any of the pieces
+        let (before_fragment, fragment): (&str, Option<&str>) = match input_str.find('#') {
+            Some(offset) => (&input_str[..offset], Some(&input_str[offset + 1..])),
+            None => (input_str, None),
+        };
+        let colon_offset: usize = before_fragment
+            .find(':')
+            .ok_or(DataUrlParseError::MalformedDataUrlError)?;
+        let comma_offset: usize = before_fragment
+            .find(',')
+            .ok_or(DataUrlParseError::MalformedDataUrlError)?;
+        if comma_offset < colon_offset {
+            return Err(DataUrlParseError::MalformedDataUrlError);
+        }
+
+        data_url.original = Some(Original {
+            scheme: before_fragment[..=colon_offset].to_string(),
+            meta_data: before_fragment[colon_offset + 1..comma_offset]
+                .split(';')
+                .map(|item| item.to_string())
+                .collect(),
+            data: Some(before_fragment[comma_offset + 1..].to_string()),
+            fragment: fragment.map(|f| f.to_string()),
+            fragment_changed: false,
+        });
+
+        #[cfg(feature = "compression")]
+        if options.decompress {
+            data_url.decompress_with_options(options)?;
+        }
+
+        Ok(data_url)
+    }
+}
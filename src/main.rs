@@ -1,26 +1,257 @@
 extern crate clap;
 
+mod graphics;
+mod preview;
+
 use atty::Stream;
-use clap::{crate_authors, crate_description, crate_version, App, Arg};
-use dataurl::DataUrl;
+use clap::{crate_description, crate_version, App, Arg, ArgMatches, SubCommand};
+use dataurl::{
+    Algorithm, ContentEncoding, DataUrl, DataUrlParseError, FontFaceOptions, HtmlOptions,
+    ImageFormat, ImageOptions, MultipartError, ParseMode, ParseOptions, StringOptions,
+};
+use encoding_rs::{EncoderResult, Encoding, UTF_8};
+use graphics::Protocol;
+use std::borrow::Cow;
 use std::env;
+use std::ffi::OsStr;
 use std::fs;
 use std::io::{self, prelude::*, Write};
+use std::path::Path;
 
-pub fn read_stdin() -> Vec<u8> {
+pub fn read_stdin(max_size: Option<usize>) -> Vec<u8> {
     let mut buffer: Vec<u8> = vec![];
 
-    match io::stdin().lock().read_to_end(&mut buffer) {
+    match read_to_end(io::stdin().lock(), &mut buffer, max_size) {
         Ok(_) => buffer,
         Err(_) => buffer,
     }
 }
 
+// Stops one byte past the limit, which is enough to tell that the input is too big
+fn read_to_end<R: Read>(
+    reader: R,
+    buffer: &mut Vec<u8>,
+    max_size: Option<usize>,
+) -> io::Result<usize> {
+    match max_size {
+        Some(max_size) => reader.take(max_size as u64 + 1).read_to_end(buffer),
+        None => {
+            let mut reader = reader;
+            reader.read_to_end(buffer)
+        }
+    }
+}
+
+// Arguments come in whatever encoding the terminal uses, which is not necessarily UTF-8
+fn arg_to_bytes(arg: &OsStr) -> Vec<u8> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::ffi::OsStrExt;
+        arg.as_bytes().to_vec()
+    }
+    #[cfg(not(unix))]
+    {
+        arg.to_string_lossy().as_bytes().to_vec()
+    }
+}
+
+// Looks at the same variables setlocale() does, e.g. LANG=de_DE.ISO-8859-1@euro yields ISO-8859-1
+fn locale_charset() -> String {
+    let locale: String = ["LC_ALL", "LC_CTYPE", "LANG"]
+        .iter()
+        .filter_map(|name| env::var(name).ok())
+        .find(|value| !value.is_empty())
+        .unwrap_or_default();
+
+    if locale == "C" || locale == "POSIX" {
+        return "US-ASCII".to_string();
+    }
+
+    match locale.split_once('.') {
+        Some((_, codeset)) => codeset.split('@').next().unwrap().to_string(),
+        None => "UTF-8".to_string(),
+    }
+}
+
+fn is_ascii_charset(charset: &str) -> bool {
+    ["ANSI_X3.4-1968", "ASCII", "US-ASCII"]
+        .iter()
+        .any(|name| name.eq_ignore_ascii_case(charset))
+}
+
+fn is_valid_charset(charset: &str) -> bool {
+    is_ascii_charset(charset) || Encoding::for_label_no_replacement(charset.as_bytes()).is_some()
+}
+
+// Plain ASCII terminals get UTF-8, same as unknown charsets
+fn locale_encoding() -> &'static Encoding {
+    let charset: String = locale_charset();
+
+    if is_ascii_charset(&charset) {
+        UTF_8
+    } else {
+        Encoding::for_label_no_replacement(charset.as_bytes()).unwrap_or(UTF_8)
+    }
+}
+
+// Characters the charset can't represent get replaced with their \u{...} escapes
+fn transcode(text: &str, charset: &str, escape_control_chars: bool) -> Vec<u8> {
+    let ascii_only: bool = is_ascii_charset(charset);
+    let mut escaped: String = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        if escape_control_chars && c.is_control() && c != '\n' && c != '\t' {
+            escaped.extend(c.escape_default());
+        } else if ascii_only && !c.is_ascii() {
+            escaped.extend(c.escape_unicode());
+        } else {
+            escaped.push(c);
+        }
+    }
+
+    let encoding: &'static Encoding =
+        Encoding::for_label_no_replacement(charset.as_bytes()).unwrap_or(UTF_8);
+    let mut encoder = encoding.new_encoder();
+    let mut output: Vec<u8> = Vec::with_capacity(escaped.len());
+    let mut remaining: &str = &escaped;
+
+    loop {
+        let (result, read) =
+            encoder.encode_from_utf8_to_vec_without_replacement(remaining, &mut output, true);
+        remaining = &remaining[read..];
+
+        match result {
+            EncoderResult::InputEmpty => break,
+            EncoderResult::OutputFull => output.reserve(remaining.len().max(16)),
+            EncoderResult::Unmappable(c) => {
+                output.extend_from_slice(c.escape_unicode().to_string().as_bytes())
+            }
+        }
+    }
+
+    output
+}
+
+fn reencode_image(data_url: &mut DataUrl, options: &ImageOptions) {
+    let size_before: usize = data_url.data().len();
+
+    if let Err(err) = data_url.reencode_image(options) {
+        eprintln!("error: Unable to re-encode image ({})", err);
+        std::process::exit(1);
+    }
+
+    eprintln!(
+        "info: Image size changed from {} to {} bytes",
+        size_before,
+        data_url.data().len()
+    );
+}
+
+// Fonts past this size are better off subset before being inlined, they block rendering until loaded
+const FONT_SIZE_WARNING_THRESHOLD: usize = 100 * 1024;
+
+fn font_face(app: &ArgMatches) {
+    let path: &str = app.value_of("FONT FILE").unwrap();
+    let mut data_url: DataUrl = DataUrl::from_file(path).unwrap_or_else(|_| {
+        eprintln!("error: Unable to read input file '{}'", path);
+        std::process::exit(1);
+    });
+
+    if !data_url.media_type().starts_with("font/") {
+        eprintln!("error: File '{}' is not a font", path);
+        std::process::exit(1);
+    }
+    if data_url.data().len() > FONT_SIZE_WARNING_THRESHOLD {
+        eprintln!(
+            "warning: Font is {} bytes large, consider subsetting it",
+            data_url.data().len()
+        );
+    }
+    data_url.set_is_base64_encoded(true);
+
+    // Family defaults to file name, minus the extension
+    let family: String = match app.value_of("FAMILY") {
+        Some(family) => family.to_string(),
+        None => Path::new(path)
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().to_string())
+            .unwrap_or_default(),
+    };
+    let options: FontFaceOptions = FontFaceOptions {
+        family,
+        weight: app.value_of("WEIGHT").map(|weight| weight.to_string()),
+        style: app.value_of("STYLE").map(|style| style.to_string()),
+        display: app.value_of("DISPLAY").map(|display| display.to_string()),
+    };
+    if let Some(descriptor) = options.invalid_descriptor() {
+        eprintln!("error: Invalid {} value", descriptor);
+        std::process::exit(1);
+    }
+
+    println!("{}", data_url.to_font_face(&options));
+}
+
+fn mime(app: &ArgMatches) {
+    let input: Vec<u8> = match app.value_of("FILE") {
+        Some(path) => fs::read(path).unwrap_or_else(|_| {
+            eprintln!("error: Unable to read input file '{}'", path);
+            std::process::exit(1);
+        }),
+        None => read_stdin(None),
+    };
+    let input: Cow<str> = String::from_utf8_lossy(&input);
+
+    if app.is_present("decode") {
+        match dataurl::multipart_to_html(&input) {
+            Ok(html) => print!("{}", html),
+            Err(err) => {
+                eprintln!(
+                    "error: {}",
+                    match err {
+                        MultipartError::MalformedMessage => "Malformed MIME message",
+                        MultipartError::MissingBoundary => "Multipart message has no boundary",
+                        MultipartError::MissingHtmlPart => "MIME message has no HTML part",
+                    }
+                );
+                std::process::exit(1);
+            }
+        }
+    } else {
+        print!("{}", dataurl::html_to_multipart(&input));
+    }
+}
+
+fn print_parse_error(err: DataUrlParseError, max_size: Option<usize>) {
+    match (err, max_size) {
+        (DataUrlParseError::LimitExceeded, Some(max_size)) => {
+            eprintln!("error: Data exceeds maximum size of {} bytes", max_size)
+        }
+        (DataUrlParseError::InvalidMediaType, _) => {
+            eprintln!("error: Media type is not a valid type/subtype pair")
+        }
+        (DataUrlParseError::InvalidParameter, _) => {
+            eprintln!("error: Parameter is not a valid attribute=value pair")
+        }
+        (DataUrlParseError::InvalidCharacter, _) => {
+            eprintln!("error: Data URL contains characters which must be percent-encoded")
+        }
+        // Same message as before the error type derived Debug, scripts may be matching on it
+        (_, _) => eprintln!("error: DataUrlParseError"),
+    }
+}
+
 fn main() {
     let app = App::new(env!("CARGO_PKG_NAME"))
         .version(crate_version!())
-        .author(format!("\n{}", crate_authors!("\n")).as_str())
+        .author(format!("\n{}", env!("CARGO_PKG_AUTHORS").replace(':', "\n")).as_str())
         .about(crate_description!())
+        .arg(
+            Arg::with_name("ALT TEXT")
+                .long("alt")
+                .multiple(false)
+                .takes_value(true)
+                .help("Sets alternative text of HTML image"),
+        )
         .arg(
             Arg::with_name("base64")
                 .short("b")
@@ -28,6 +259,12 @@ fn main() {
                 .multiple(false)
                 .help("Enforces base64 encoding"),
         )
+        .arg(
+            Arg::with_name("canonical")
+                .long("canonical")
+                .multiple(false)
+                .help("Parses input and outputs it as canonical data URL"),
+        )
         .arg(
             Arg::with_name("ENCODING")
                 .short("c")
@@ -36,6 +273,14 @@ fn main() {
                 .takes_value(true)
                 .help("Sets custom encoding parameter"),
         )
+        .arg(
+            Arg::with_name("COMPRESSION")
+                .long("compress")
+                .multiple(false)
+                .takes_value(true)
+                .possible_values(&["gzip", "deflate", "br"])
+                .help("Compresses data before encoding it"),
+        )
         .arg(
             Arg::with_name("decode")
                 .short("d")
@@ -51,6 +296,31 @@ fn main() {
                 .takes_value(true)
                 .help("Appends URL fragment"),
         )
+        .arg(
+            Arg::with_name("FORMAT")
+                .long("format")
+                .multiple(false)
+                .takes_value(true)
+                .possible_values(&["url", "html"])
+                .help("Sets output format"),
+        )
+        .arg(
+            Arg::with_name("PROTOCOL")
+                .long("graphics-protocol")
+                .multiple(false)
+                .takes_value(true)
+                .possible_values(&["kitty", "iterm2", "sixel"])
+                .help("Overrides terminal graphics protocol used by --show"),
+        )
+        .arg(
+            Arg::with_name("HASH ALGORITHM")
+                .long("hash")
+                .multiple(false)
+                .takes_value(true)
+                .possible_values(&["sha256", "sha384", "sha512"])
+                .conflicts_with("SRI ALGORITHM")
+                .help("Outputs integrity hash instead of data URL"),
+        )
         .arg(
             Arg::with_name("INPUT FILE")
                 .short("i")
@@ -67,6 +337,34 @@ fn main() {
                 .takes_value(true)
                 .help("Specifies output file"),
         )
+        .arg(
+            Arg::with_name("OUTPUT ENCODING")
+                .long("output-charset")
+                .multiple(false)
+                .takes_value(true)
+                .help("Sets encoding of decoded text (defaults to terminal locale)"),
+        )
+        .arg(
+            Arg::with_name("MAX SIZE")
+                .long("max-size")
+                .multiple(false)
+                .takes_value(true)
+                .help("Limits size of input and decoded data, in bytes"),
+        )
+        .arg(
+            Arg::with_name("WIDTH")
+                .long("wrap")
+                .multiple(false)
+                .takes_value(true)
+                .help("Wraps base64-encoded output into lines of given width (76 for MIME)"),
+        )
+        .arg(
+            Arg::with_name("MAX DIMENSION")
+                .long("max-dimension")
+                .multiple(false)
+                .takes_value(true)
+                .help("Scales image down to fit given width and height"),
+        )
         .arg(
             Arg::with_name("MEDIA TYPE")
                 .short("t")
@@ -75,11 +373,123 @@ fn main() {
                 .takes_value(true)
                 .help("Sets custom media type"),
         )
+        .arg(
+            Arg::with_name("preview")
+                .long("preview")
+                .multiple(false)
+                .help("Outputs human-readable preview of decoded data (default for terminals)"),
+        )
+        .arg(
+            Arg::with_name("QUALITY")
+                .long("quality")
+                .multiple(false)
+                .takes_value(true)
+                .help("Sets quality of re-encoded JPEG image, from 1 to 100"),
+        )
+        .arg(
+            Arg::with_name("SRCSET FILE")
+                .long("srcset")
+                .multiple(true)
+                .number_of_values(1)
+                .takes_value(true)
+                .help("Adds higher density version of HTML image, can be used more than once"),
+        )
+        .arg(
+            Arg::with_name("strict")
+                .long("strict")
+                .multiple(false)
+                .help("Rejects input which doesn't conform to RFC 2397"),
+        )
+        .arg(
+            Arg::with_name("show")
+                .long("show")
+                .multiple(false)
+                .help("Displays decoded image in terminal, falls back to preview"),
+        )
+        .arg(
+            Arg::with_name("SRI ALGORITHM")
+                .long("sri")
+                .multiple(false)
+                .takes_value(true)
+                .possible_values(&["sha256", "sha384", "sha512"])
+                .help("Outputs integrity hash along with data URL"),
+        )
+        .arg(
+            Arg::with_name("IMAGE FORMAT")
+                .long("to")
+                .multiple(false)
+                .takes_value(true)
+                .possible_values(&["jpeg", "png", "webp"])
+                .help("Re-encodes image into given format"),
+        )
         .arg(Arg::with_name("INPUT").help("Input string").required(false))
+        .subcommand(
+            SubCommand::with_name("font")
+                .version(crate_version!())
+                .about("Outputs @font-face rule with font embedded as data URL")
+                .arg(
+                    Arg::with_name("DISPLAY")
+                        .long("display")
+                        .takes_value(true)
+                        .possible_values(&["auto", "block", "swap", "fallback", "optional"])
+                        .help("Sets font-display"),
+                )
+                .arg(
+                    Arg::with_name("FAMILY")
+                        .long("family")
+                        .takes_value(true)
+                        .help("Sets font-family (defaults to file name)"),
+                )
+                .arg(
+                    Arg::with_name("STYLE")
+                        .long("style")
+                        .takes_value(true)
+                        .help("Sets font-style"),
+                )
+                .arg(
+                    Arg::with_name("WEIGHT")
+                        .long("weight")
+                        .takes_value(true)
+                        .help("Sets font-weight"),
+                )
+                .arg(
+                    Arg::with_name("FONT FILE")
+                        .help("WOFF2, WOFF, TTF or OTF file")
+                        .required(true),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("mime")
+                .version(crate_version!())
+                .about(
+                    "Converts HTML with data URLs into multipart/related MIME message (for email)",
+                )
+                .arg(
+                    Arg::with_name("decode")
+                        .short("d")
+                        .long("decode")
+                        .help("Converts MIME message back into HTML with data URLs"),
+                )
+                .arg(
+                    Arg::with_name("FILE")
+                        .help("HTML file or MIME message (defaults to STDIN)")
+                        .required(false),
+                ),
+        )
         .get_matches();
 
+    if let Some(font_app) = app.subcommand_matches("font") {
+        font_face(font_app);
+        std::process::exit(0);
+    }
+    if let Some(mime_app) = app.subcommand_matches("mime") {
+        mime(mime_app);
+        std::process::exit(0);
+    }
+
     //////////////////////////////////////////////////////////////////////////
 
+    let canonical_mode_enabled: bool = app.is_present("canonical");
     let decode_mode_enabled: bool = app.is_present("decode");
     let string_input_set: bool = app.is_present("INPUT");
     // let stdin_is_a_tty: bool = !io::stdio::stdin_raw().isatty();
@@ -104,6 +514,38 @@ fn main() {
     }
     let file_input_set = file_input_set;
     let file_output_set = file_output_set;
+    let output_charset: Option<&str> = app.value_of("OUTPUT ENCODING");
+    if let Some(charset) = output_charset {
+        if !is_valid_charset(charset) {
+            eprintln!("error: Invalid encoding '{}'", charset);
+            std::process::exit(1);
+        }
+    }
+
+    let max_size: Option<usize> = app.value_of("MAX SIZE").map(|max_size| {
+        max_size.parse().unwrap_or_else(|_| {
+            eprintln!("error: Invalid size '{}'", max_size);
+            std::process::exit(1);
+        })
+    });
+    let parse_options: ParseOptions = ParseOptions {
+        mode: if app.is_present("strict") {
+            ParseMode::Rfc2397Strict
+        } else {
+            ParseMode::Lenient
+        },
+        max_input_len: max_size,
+        max_decoded_len: max_size,
+        ignore_base64_whitespace: !app.is_present("strict"),
+        ..ParseOptions::default()
+    };
+    let wrap: Option<usize> = app.value_of("WIDTH").map(|width| match width.parse() {
+        Ok(width) if width > 0 => width,
+        _ => {
+            eprintln!("error: Invalid width '{}'", width);
+            std::process::exit(1);
+        }
+    });
 
     //////////////////////////////////////////////////////////////////////////
 
@@ -120,10 +562,13 @@ fn main() {
     //////////////////////////////////////////////////////////////////////////
 
     let input: Vec<u8> = if string_input_set {
-        app.value_of("INPUT").unwrap().as_bytes().to_vec()
+        arg_to_bytes(app.value_of_os("INPUT").unwrap())
     } else if file_input_set {
-        match fs::read(input_file_path) {
-            Ok(input_file_data) => input_file_data,
+        let mut input_file_data: Vec<u8> = vec![];
+        match fs::File::open(input_file_path)
+            .and_then(|file| read_to_end(file, &mut input_file_data, max_size))
+        {
+            Ok(_) => input_file_data,
             Err(_) => {
                 eprintln!("error: Unable to read input file '{}'", input_file_path);
                 std::process::exit(1);
@@ -131,81 +576,273 @@ fn main() {
         }
     } else {
         // TODO: make it hang here, waiting on input from STDIN the way GNU's `base64` or `cat` do
-        read_stdin()
+        read_stdin(max_size)
     };
 
+    if let Some(max_size) = max_size {
+        if input.len() > max_size {
+            eprintln!("error: Input exceeds maximum size of {} bytes", max_size);
+            std::process::exit(1);
+        }
+    }
+
     //////////////////////////////////////////////////////////////////////////
 
-    if decode_mode_enabled {
-        // TODO: ideally the program needs to check the current terminal locale (encoding), and not just assume it's UTF-8
+    if canonical_mode_enabled {
+        let input_as_string: String = String::from_utf8_lossy(&input).to_string();
+
+        std::process::exit(
+            match DataUrl::parse_with_options(&input_as_string, &parse_options) {
+                Ok(data_url) => {
+                    println!("{}", data_url.to_canonical_string());
+                    0
+                }
+                Err(err) => {
+                    print_parse_error(err, max_size);
+                    1
+                }
+            },
+        );
+    } else if decode_mode_enabled {
         let input_as_string: String = String::from_utf8_lossy(&input).to_string();
 
-        std::process::exit(match DataUrl::parse(&input_as_string) {
-            Ok(data_url) => {
-                if !stdout_is_a_tty || file_output_set || data_url.is_binary() {
-                    // Write raw bytes if the output is a file, or if the contents of this data URL has binary format
+        std::process::exit(
+            match DataUrl::parse_with_options(&input_as_string, &parse_options) {
+                Ok(mut data_url) => {
+                    // Compressed payloads get transparently decompressed
+                    let decompressed = match max_size {
+                        Some(max_size) => data_url.decompress_with_limit(max_size),
+                        None => data_url.decompress(),
+                    };
+                    if let Err(err) = decompressed {
+                        eprintln!("error: Unable to decompress data ({})", err);
+                        std::process::exit(1);
+                    }
+
+                    let terminal_output: bool = stdout_is_a_tty && !file_output_set;
+                    let show_enabled: bool = app.is_present("show") || app.is_present("PROTOCOL");
+                    let protocol: Option<Protocol> = match app.value_of("PROTOCOL") {
+                        Some(name) => Protocol::from_name(name),
+                        None => Protocol::detect(),
+                    };
+                    let graphics: Option<Vec<u8>> = protocol
+                        .filter(|_| show_enabled)
+                        .and_then(|protocol| graphics::render(&data_url, protocol));
+                    let output: Cow<[u8]> = if let Some(graphics) = graphics {
+                        Cow::Owned(graphics)
+                    } else if show_enabled || app.is_present("preview") || terminal_output {
+                        // Raw binary data and control characters could mess up the terminal
+                        let charset: String = output_charset
+                            .map(|charset| charset.to_string())
+                            .unwrap_or_else(locale_charset);
+                        Cow::Owned(transcode(&preview::preview(&data_url), &charset, true))
+                    } else if data_url.is_binary() {
+                        Cow::Borrowed(data_url.data())
+                    } else if let Some(charset) = output_charset {
+                        Cow::Owned(transcode(&data_url.text(), charset, false))
+                    } else {
+                        Cow::Borrowed(data_url.data())
+                    };
+
                     if file_output_set {
                         let mut handle = fs::File::create(output_file_path).unwrap();
-                        handle.write_all(data_url.data()).unwrap();
+                        handle.write_all(&output).unwrap();
                     } else {
                         let stdout = io::stdout();
                         let mut handle = stdout.lock();
-                        handle.write_all(data_url.data()).unwrap();
+                        handle.write_all(&output).unwrap();
                     }
-                } else {
-                    // When printing the result directly into the terminal, we have to convert data into UTF-8 (must account for non-US-ASCII/UTF-8 charsets)
-                    print!("{}", data_url.text());
+                    0
                 }
-                0
-            }
-            Err(err) => {
-                eprintln!("error: {:?}", err);
-                1
-            }
-        });
+                Err(err) => {
+                    print_parse_error(err, max_size);
+                    1
+                }
+            },
+        );
     } else {
-        let mut data_url = DataUrl::new();
+        let input_is_ascii: bool = input.is_ascii();
+        let mut builder = DataUrl::builder()
+            .data(input)
+            .base64(app.is_present("base64") || wrap.is_some());
 
-        data_url.set_data(&input);
+        if app.is_present("ENCODING") {
+            let charset: &str = app.value_of("ENCODING").unwrap();
 
-        if app.is_present("base64") {
-            data_url.set_is_base64_encoded(true);
+            builder = match builder.charset(charset) {
+                Ok(builder) => builder,
+                Err(_) => {
+                    eprintln!("error: Invalid encoding '{}'", charset);
+                    std::process::exit(1);
+                }
+            };
+        } else {
+            // Non-ASCII argument inputs are labeled with the terminal's charset, UTF-8 unless it says otherwise
+            if string_input_set && !input_is_ascii {
+                builder = builder.charset(locale_encoding().name()).unwrap();
+            }
         }
 
-        if app.is_present("ENCODING") {
-            let charset: &str = app.value_of("ENCODING").unwrap();
-            let success: bool = data_url.set_charset(Some(charset.to_string()));
+        if app.is_present("MEDIA TYPE") {
+            let media_type: &str = app.value_of("MEDIA TYPE").unwrap();
 
-            if !success {
-                eprintln!("error: Invalid encoding '{}'", charset);
-                std::process::exit(1);
+            builder = match builder.media_type(media_type) {
+                Ok(builder) => builder,
+                Err(_) => {
+                    eprintln!("error: Invalid media type '{}'", media_type);
+                    std::process::exit(1);
+                }
+            };
+        } else if app.value_of("FORMAT") == Some("html") {
+            // The tag depends on the media type, e.g. images go into <img> rather than <object type="text/plain">
+            builder = builder.sniff_media_type();
+        }
+
+        if app.is_present("FRAGMENT") {
+            builder = builder.fragment(app.value_of("FRAGMENT").unwrap());
+        }
+
+        let mut data_url: DataUrl = builder.build();
+
+        // Textual input is in the terminal's charset (arguments) or UTF-8 (files), yet it has to be stored in the charset the data URL declares
+        if app.is_present("ENCODING") && !data_url.is_binary() {
+            if let Some(encoding) =
+                Encoding::for_label_no_replacement(data_url.charset().as_bytes())
+            {
+                let source_encoding: &'static Encoding = if string_input_set {
+                    locale_encoding()
+                } else {
+                    UTF_8
+                };
+                let encoded: Vec<u8> = encoding
+                    .encode(
+                        &source_encoding
+                            .decode_without_bom_handling(data_url.data())
+                            .0,
+                    )
+                    .0
+                    .into_owned();
+                data_url.set_data_owned(encoded);
             }
+        }
 
-            // TODO: encode data into provided charset, if different
-        } else {
-            // TODO: ideally the program needs to check the current terminal locale (encoding), and not just assume it's UTF-8
+        if app.is_present("MAX DIMENSION")
+            || app.is_present("IMAGE FORMAT")
+            || app.is_present("QUALITY")
+        {
+            let mut options: ImageOptions = ImageOptions {
+                format: app
+                    .value_of("IMAGE FORMAT")
+                    .and_then(ImageFormat::from_name),
+                ..ImageOptions::default()
+            };
+            if let Some(max_dimension) = app.value_of("MAX DIMENSION") {
+                match max_dimension.parse::<u32>() {
+                    Ok(max_dimension) if max_dimension > 0 => {
+                        options.max_dimension = Some(max_dimension)
+                    }
+                    _ => {
+                        eprintln!("error: Invalid dimension '{}'", max_dimension);
+                        std::process::exit(1);
+                    }
+                }
+            }
+            if let Some(quality) = app.value_of("QUALITY") {
+                match quality.parse::<u8>() {
+                    Ok(quality) if (1..=100).contains(&quality) => options.quality = quality,
+                    _ => {
+                        eprintln!("error: Invalid quality '{}'", quality);
+                        std::process::exit(1);
+                    }
+                }
 
-            // Automatically enforce ;charset=UTF-8 for non-ascii argument inputs
-            if string_input_set && !String::from_utf8_lossy(&input).to_string().is_ascii() {
-                data_url.set_charset(Some("UTF-8".to_string()));
+                // Without --to, JPEG input stays JPEG, yet it only gets re-encoded if told how
+                match options.format {
+                    Some(ImageFormat::Jpeg) => {}
+                    Some(_) => {
+                        eprintln!(
+                            "error: Quality only applies to JPEG, PNG and WebP output is lossless"
+                        );
+                        std::process::exit(1);
+                    }
+                    None if data_url.sniffed_media_type() == Some("image/jpeg") => {
+                        options.format = Some(ImageFormat::Jpeg)
+                    }
+                    None => eprintln!("warning: Quality only affects JPEG output, ignoring it"),
+                }
             }
+            reencode_image(&mut data_url, &options);
         }
 
-        if app.is_present("MEDIA TYPE") {
-            let media_type: &str = app.value_of("MEDIA TYPE").unwrap();
-            let success: bool = data_url.set_media_type(Some(media_type.to_string()));
+        if app.is_present("COMPRESSION") {
+            let content_encoding: &str = app.value_of("COMPRESSION").unwrap();
 
-            if !success {
-                eprintln!("error: Invalid media type '{}'", media_type);
+            if let Err(err) =
+                data_url.compress(ContentEncoding::from_name(content_encoding).unwrap())
+            {
+                eprintln!("error: Unable to compress data ({})", err);
                 std::process::exit(1);
             }
         }
 
-        if app.is_present("FRAGMENT") {
-            data_url.set_fragment(Some(app.value_of("FRAGMENT").unwrap().to_string()));
+        if app.is_present("HASH ALGORITHM") {
+            let algorithm: &str = app.value_of("HASH ALGORITHM").unwrap();
+            println!(
+                "{}",
+                data_url.digest(Algorithm::from_name(algorithm).unwrap())
+            );
+            std::process::exit(0);
         }
 
-        println!("{}", data_url.to_string());
+        let integrity: Option<String> = app
+            .value_of("SRI ALGORITHM")
+            .map(|algorithm| data_url.digest(Algorithm::from_name(algorithm).unwrap()));
+
+        if app.value_of("FORMAT") == Some("html") {
+            let mut srcset: Vec<(String, String)> = vec![];
+            for (i, path) in app
+                .values_of("SRCSET FILE")
+                .into_iter()
+                .flatten()
+                .enumerate()
+            {
+                let mut candidate: DataUrl = DataUrl::from_file(path).unwrap_or_else(|_| {
+                    eprintln!("error: Unable to read input file '{}'", path);
+                    std::process::exit(1);
+                });
+                if let Some(media_type) = data_url.media_type_no_default() {
+                    candidate.set_media_type(Some(media_type));
+                }
+                // Percent-encoding binary data would make candidates about three times as long
+                candidate
+                    .set_is_base64_encoded(data_url.is_base64_encoded() || candidate.is_binary());
+
+                // Density is how much wider the image is, files are assumed to go 2x, 3x, etc otherwise
+                let density: f64 = match (data_url.image_dimensions(), candidate.image_dimensions())
+                {
+                    (Some((width, _)), Some((candidate_width, _))) if width > 0 => {
+                        (candidate_width as f64 / width as f64 * 100.0).round() / 100.0
+                    }
+                    _ => (i + 2) as f64,
+                };
+                srcset.push((candidate.to_string(), format!("{}x", density)));
+            }
+
+            let options: HtmlOptions = HtmlOptions {
+                integrity,
+                alt: app.value_of("ALT TEXT").map(|alt| alt.to_string()),
+                srcset,
+            };
+            println!("{}", data_url.to_html(&options));
+        } else {
+            println!(
+                "{}",
+                data_url.to_string_with_options(&StringOptions { wrap })
+            );
+            if let Some(i) = integrity {
+                println!("{}", i);
+            }
+        }
 
         std::process::exit(0);
     }
@@ -0,0 +1,46 @@
+use ::mime::Mime;
+
+use crate::DataUrl;
+
+impl DataUrl {
+    pub fn mime(&self) -> Mime {
+        // Parameters mime can't represent get dropped before giving up on the media type itself
+        let mut media_type_only: DataUrl = DataUrl::new();
+        media_type_only.media_type = self.media_type.clone();
+        media_type_only.charset = self.charset.clone();
+
+        self.content_type()
+            .parse()
+            .or_else(|_| media_type_only.content_type().parse())
+            // Browsers treat data URLs with unparsable media types as text/plain;charset=US-ASCII
+            .unwrap_or_else(|_| DataUrl::new().content_type().parse().unwrap())
+    }
+
+    pub fn set_mime(&mut self, mime: &Mime) -> bool {
+        let mut success: bool = self.set_media_type(Some(mime.essence_str().to_string()));
+
+        // Parameters get replaced as a whole, the same way the media type does; content-encoding isn't part of it
+        let names: Vec<String> = self
+            .parameters
+            .iter()
+            .map(|(n, _)| n.clone())
+            .filter(|n| n != "content-encoding")
+            .collect();
+        for name in names {
+            self.set_parameter(&name, None);
+        }
+        self.set_charset(None);
+
+        for (name, value) in mime.params() {
+            success &= self.set_parameter(name.as_str(), Some(value.as_str().to_string()));
+        }
+
+        success
+    }
+}
+
+impl From<&DataUrl> for Mime {
+    fn from(data_url: &DataUrl) -> Self {
+        data_url.mime()
+    }
+}
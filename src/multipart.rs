@@ -0,0 +1,455 @@
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use core::fmt;
+use encoding_rs::{Encoding, UTF_8};
+
+use crate::{codec, DataUrl};
+
+// RFC 2045 caps encoded lines at 76 characters
+const LINE_WIDTH: usize = 76;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MultipartError {
+    MalformedMessage, // No blank line after headers, no closing boundary, or corrupt base64
+    MissingBoundary,  // Multipart content type without boundary parameter
+    MissingHtmlPart,  // Nothing to use as the root document
+}
+
+impl fmt::Display for MultipartError {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str(match self {
+            MultipartError::MalformedMessage => "malformed MIME message",
+            MultipartError::MissingBoundary => "multipart message has no boundary",
+            MultipartError::MissingHtmlPart => "MIME message has no HTML part",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for MultipartError {}
+
+// FNV-1a, only there to tell messages apart, so nothing cryptographic is needed
+fn fnv1a(data: &[u8]) -> u64 {
+    data.iter().fold(0xcbf2_9ce4_8422_2325, |hash, b| {
+        (hash ^ *b as u64).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+// Attribute values, CSS url() and srcset candidates
+fn is_url_start_boundary(c: u8) -> bool {
+    matches!(c, b'"' | b'\'' | b'(' | b'=' | b',') || c.is_ascii_whitespace()
+}
+
+// Quoted URLs end at the matching quote, unquoted ones wherever the attribute, url() or srcset candidate does
+fn find_url_end(bytes: &[u8], start: usize) -> usize {
+    let quote: Option<u8> = start
+        .checked_sub(1)
+        .map(|i| bytes[i])
+        .filter(|c| matches!(c, b'"' | b'\''));
+    let is_end = |c: u8| match quote {
+        Some(quote) => c == quote,
+        None => matches!(c, b'"' | b'\'' | b')' | b'<' | b'>') || c.is_ascii_whitespace(),
+    };
+
+    bytes[start..]
+        .iter()
+        .position(|c| is_end(*c))
+        .map_or(bytes.len(), |length| start + length)
+}
+
+// Calls replace() for each URL with the given scheme, keeping the URL if it returns None
+fn replace_urls<F>(html: &str, scheme: &str, mut replace: F) -> String
+where
+    F: FnMut(&str) -> Option<String>,
+{
+    let bytes: &[u8] = html.as_bytes();
+    let mut output: String = String::with_capacity(html.len());
+    let mut copied: usize = 0;
+    let mut i: usize = 0;
+
+    while i + scheme.len() <= bytes.len() {
+        let is_match: bool = bytes[i..i + scheme.len()].eq_ignore_ascii_case(scheme.as_bytes())
+            && (i == 0 || is_url_start_boundary(bytes[i - 1]));
+        if !is_match {
+            i += 1;
+            continue;
+        }
+
+        let end: usize = find_url_end(bytes, i);
+        if let Some(replacement) = replace(&html[i..end]) {
+            output.push_str(&html[copied..i]);
+            output.push_str(&replacement);
+            copied = end;
+        }
+        i = end;
+    }
+    output.push_str(&html[copied..]);
+
+    output
+}
+
+// RFC 2045 token characters
+fn is_token(value: &str) -> bool {
+    !value.is_empty()
+        && value
+            .bytes()
+            .all(|b| b.is_ascii_graphic() && !b"()<>@,;:\\\"/[]?=".contains(&b))
+}
+
+// None if some parameter can't be put into a header safely, line breaks would let it add headers of its own
+fn content_type(data_url: &DataUrl) -> Option<String> {
+    let mut content_type: String = data_url.media_type().to_string();
+
+    if let Some(charset) = data_url.charset_no_default() {
+        content_type += &format!("; charset={}", charset);
+    }
+    for (name, value) in data_url.parameters() {
+        if !is_token(name) || value.chars().any(|c| c.is_control()) {
+            return None;
+        }
+        content_type += &format!(
+            "; {}=\"{}\"",
+            name,
+            value.replace('\\', "\\\\").replace('"', "\\\"")
+        );
+    }
+
+    Some(content_type)
+}
+
+// Takes HTML with data URLs in it, returns multipart/related message with each payload as separate part, referenced via cid: URLs
+pub fn html_to_multipart(html: &str) -> String {
+    // Parts are told apart by their URLs, which makes identical payloads share one part
+    let mut parts: Vec<(String, String, String, DataUrl)> = Vec::new();
+
+    let html: String = replace_urls(html, "data:", |url| {
+        let mut data_url: DataUrl = DataUrl::parse(url).ok()?;
+        let content_type: String = content_type(&data_url)?;
+        // Fragments stay in the HTML, so that identical payloads can share one part
+        let fragment: Option<String> = url.split_once('#').map(|(_, f)| f.to_string());
+        data_url.set_fragment(None);
+        let key: String = data_url.to_string();
+        let content_id: String = match parts.iter().find(|(k, _, _, _)| *k == key) {
+            Some((_, content_id, _, _)) => content_id.clone(),
+            None => {
+                let content_id: String = format!("part{}@dataurl", parts.len() + 1);
+                parts.push((key, content_id.clone(), content_type, data_url));
+                content_id
+            }
+        };
+        Some(match fragment {
+            Some(fragment) => format!("cid:{}#{}", content_id, fragment),
+            None => format!("cid:{}", content_id),
+        })
+    });
+
+    // Base64 never contains dashes, so the boundary can't show up in any of the parts
+    let boundary: String = format!("----=_dataurl_{:016x}", fnv1a(html.as_bytes()));
+    let mut message: String = format!(
+        "MIME-Version: 1.0\r\nContent-Type: multipart/related; boundary=\"{}\"; type=\"text/html\"\r\n\r\n",
+        boundary
+    );
+
+    message += &format!(
+        "--{}\r\nContent-Type: text/html; charset=UTF-8\r\nContent-Transfer-Encoding: base64\r\n\r\n{}",
+        boundary,
+        codec::wrap_lines(&STANDARD.encode(html.as_bytes()), LINE_WIDTH)
+    );
+    for (_, content_id, content_type, data_url) in &parts {
+        message += &format!(
+            "--{}\r\nContent-Type: {}\r\nContent-Transfer-Encoding: base64\r\nContent-ID: <{}>\r\nContent-Disposition: inline\r\n\r\n{}",
+            boundary,
+            content_type,
+            content_id,
+            codec::wrap_lines(&STANDARD.encode(data_url.data()), LINE_WIDTH)
+        );
+    }
+    message += &format!("--{}--\r\n", boundary);
+
+    message
+}
+
+struct Part<'a> {
+    headers: Vec<(String, String)>,
+    body: &'a str,
+}
+
+impl Part<'_> {
+    fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    // Media type, lowercased, along with its parameters
+    fn content_type(&self) -> (String, Vec<(String, String)>) {
+        let value: &str = self.header("Content-Type").unwrap_or("text/plain");
+        let mut items = split_parameters(value).into_iter();
+        let media_type: String = items.next().unwrap_or_default().trim().to_lowercase();
+        let parameters: Vec<(String, String)> = items
+            .filter_map(|item| {
+                let (name, value) = item.split_once('=')?;
+                let value: &str = value.trim();
+                let value: String = match value.strip_prefix('"').and_then(|v| v.strip_suffix('"'))
+                {
+                    Some(quoted) => unescape_quoted_string(quoted),
+                    None => value.to_string(),
+                };
+                Some((name.trim().to_lowercase(), value))
+            })
+            .collect();
+
+        (media_type, parameters)
+    }
+
+    // Corrupt base64 makes the whole message malformed, rather than the part silently turning up empty
+    fn decoded_body(&self) -> Result<Vec<u8>, MultipartError> {
+        let encoding: String = self
+            .header("Content-Transfer-Encoding")
+            .unwrap_or("7bit")
+            .trim()
+            .to_lowercase();
+
+        match encoding.as_str() {
+            "base64" => {
+                let stripped: String = self
+                    .body
+                    .chars()
+                    .filter(|c| !c.is_ascii_whitespace())
+                    .collect();
+                codec::base64_decode(stripped.as_bytes()).ok_or(MultipartError::MalformedMessage)
+            }
+            "quoted-printable" => Ok(decode_quoted_printable(self.body)),
+            _ => Ok(self.body.as_bytes().to_vec()),
+        }
+    }
+}
+
+// Backslash makes whatever character follows it literal
+fn unescape_quoted_string(value: &str) -> String {
+    let mut result: String = String::with_capacity(value.len());
+    let mut chars = value.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => result.extend(chars.next()),
+            _ => result.push(c),
+        }
+    }
+
+    result
+}
+
+// Splits on semicolons which aren't inside of quotes
+fn split_parameters(value: &str) -> Vec<&str> {
+    let mut items: Vec<&str> = Vec::new();
+    let mut start: usize = 0;
+    let mut in_quotes: bool = false;
+    let mut escaped: bool = false;
+
+    for (i, c) in value.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if in_quotes => escaped = true,
+            '"' => in_quotes = !in_quotes,
+            ';' if !in_quotes => {
+                items.push(&value[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    items.push(&value[start..]);
+
+    items
+}
+
+fn decode_quoted_printable(body: &str) -> Vec<u8> {
+    let bytes: &[u8] = body.as_bytes();
+    let mut output: Vec<u8> = Vec::with_capacity(bytes.len());
+    let mut i: usize = 0;
+
+    while i < bytes.len() {
+        if bytes[i] == b'=' {
+            // Soft line break
+            if bytes[i + 1..].starts_with(b"\r\n") {
+                i += 3;
+                continue;
+            } else if bytes[i + 1..].starts_with(b"\n") {
+                i += 2;
+                continue;
+            }
+            let hex_digits: Option<u8> = bytes
+                .get(i + 1..i + 3)
+                .and_then(|h| core::str::from_utf8(h).ok())
+                .and_then(|h| u8::from_str_radix(h, 16).ok());
+            if let Some(byte) = hex_digits {
+                output.push(byte);
+                i += 3;
+                continue;
+            }
+        }
+        output.push(bytes[i]);
+        i += 1;
+    }
+
+    output
+}
+
+// Headers end at the first empty line; lines starting with whitespace continue the previous header
+fn parse_part(input: &str) -> Result<Part<'_>, MultipartError> {
+    let (head, body): (&str, &str) = if let Some(i) = input.find("\r\n\r\n") {
+        (&input[..i], &input[i + 4..])
+    } else if let Some(i) = input.find("\n\n") {
+        (&input[..i], &input[i + 2..])
+    } else if input.trim().is_empty() {
+        ("", "")
+    } else {
+        return Err(MultipartError::MalformedMessage);
+    };
+    let mut headers: Vec<(String, String)> = Vec::new();
+
+    for line in head.lines() {
+        if line.starts_with([' ', '\t']) {
+            if let Some((_, value)) = headers.last_mut() {
+                value.push(' ');
+                value.push_str(line.trim());
+            }
+        } else if let Some((name, value)) = line.split_once(':') {
+            headers.push((name.trim().to_string(), value.trim().to_string()));
+        }
+    }
+
+    Ok(Part { headers, body })
+}
+
+fn split_multipart<'a>(body: &'a str, boundary: &str) -> Result<Vec<&'a str>, MultipartError> {
+    let delimiter: String = format!("--{}", boundary);
+    let mut parts: Vec<&str> = Vec::new();
+    let mut current: Option<usize> = None;
+    let mut offset: usize = 0;
+
+    for line in body.split_inclusive('\n') {
+        let trimmed: &str = line.trim_end();
+        if trimmed.starts_with(&delimiter) {
+            // The line break before the delimiter belongs to the delimiter
+            if let Some(start) = current {
+                let end: usize = if body[..offset].ends_with("\r\n") {
+                    offset - 2
+                } else if body[..offset].ends_with('\n') {
+                    offset - 1
+                } else {
+                    offset
+                };
+                parts.push(&body[start..end.max(start)]);
+            }
+            if trimmed[delimiter.len()..].starts_with("--") {
+                return Ok(parts);
+            }
+            current = Some(offset + line.len());
+        }
+        offset += line.len();
+    }
+
+    Err(MultipartError::MalformedMessage)
+}
+
+fn decode_text(data: &[u8], charset: Option<&str>) -> String {
+    let encoding: &'static Encoding = charset
+        .and_then(|label| Encoding::for_label_no_replacement(label.as_bytes()))
+        .unwrap_or(UTF_8);
+
+    encoding.decode_with_bom_removal(data).0.into_owned()
+}
+
+// The reverse of html_to_multipart(): puts parts referenced via cid: URLs back into the HTML as data URLs
+pub fn multipart_to_html(message: &str) -> Result<String, MultipartError> {
+    let message: Part = parse_part(message)?;
+    let (media_type, parameters) = message.content_type();
+    let parameter = |name: &str| {
+        parameters
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, value)| value.as_str())
+    };
+
+    if media_type == "text/html" {
+        return Ok(decode_text(&message.decoded_body()?, parameter("charset")));
+    } else if !media_type.starts_with("multipart/") {
+        return Err(MultipartError::MissingHtmlPart);
+    }
+
+    let boundary: &str = parameter("boundary").ok_or(MultipartError::MissingBoundary)?;
+    let parts: Vec<Part> = split_multipart(message.body, boundary)?
+        .into_iter()
+        .map(parse_part)
+        .collect::<Result<Vec<Part>, MultipartError>>()?;
+    let content_id = |part: &Part| {
+        part.header("Content-ID").map(|id| {
+            id.trim()
+                .trim_start_matches('<')
+                .trim_end_matches('>')
+                .to_string()
+        })
+    };
+
+    // Root is the one the start parameter points to, or the first HTML part
+    let root: &Part = match parameter("start") {
+        Some(start) => {
+            let start: &str = start.trim().trim_start_matches('<').trim_end_matches('>');
+            parts
+                .iter()
+                .find(|part| content_id(part).as_deref() == Some(start))
+        }
+        None => parts
+            .iter()
+            .find(|part| part.content_type().0 == "text/html"),
+    }
+    .ok_or(MultipartError::MissingHtmlPart)?;
+    let (_, root_parameters) = root.content_type();
+    let root_charset: Option<&str> = root_parameters
+        .iter()
+        .find(|(name, _)| name == "charset")
+        .map(|(_, value)| value.as_str());
+    let html: String = decode_text(&root.decoded_body()?, root_charset);
+
+    let mut resources: BTreeMap<String, DataUrl> = BTreeMap::new();
+    for part in &parts {
+        if let Some(id) = content_id(part) {
+            let (media_type, parameters) = part.content_type();
+            let mut data_url: DataUrl = DataUrl::new();
+            data_url.set_media_type(Some(media_type));
+            for (name, value) in parameters {
+                if name == "charset" {
+                    data_url.set_charset(Some(value));
+                } else {
+                    data_url.set_parameter(&name, Some(value));
+                }
+            }
+            data_url.set_data_owned(part.decoded_body()?);
+            data_url.set_is_base64_encoded(true);
+            resources.insert(id, data_url);
+        }
+    }
+
+    Ok(replace_urls(&html, "cid:", |url| {
+        let (id, fragment): (&str, Option<&str>) = match url[4..].split_once('#') {
+            Some((id, fragment)) => (id, Some(fragment)),
+            None => (&url[4..], None),
+        };
+        let id = percent_encoding::percent_decode_str(id).decode_utf8_lossy();
+        let mut data_url: String = resources.get(id.as_ref())?.to_browser_string();
+
+        if let Some(fragment) = fragment {
+            data_url += "#";
+            data_url += fragment;
+        }
+
+        // Media types may contain quotes, which would end the attribute the URL is in
+        Some(data_url.replace('"', "%22").replace('\'', "%27"))
+    }))
+}
@@ -0,0 +1,61 @@
+use crate::DataUrlParseError;
+
+// How forgiving the parser is
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum ParseMode {
+    Rfc2397Strict, // Rejects anything outside of RFC 2397's grammar
+    Whatwg,        // Follows the data: URL processor of the Fetch standard
+    #[default]
+    Lenient, // Makes sense of just about anything, the way parse() always has
+}
+
+// Parsing mode and limits for untrusted input, None means unlimited
+#[derive(Clone, Debug, Default)]
+pub struct ParseOptions {
+    pub mode: ParseMode,                  // Lenient by default
+    pub max_input_len: Option<usize>,     // Length of the whole input, in bytes
+    pub max_decoded_len: Option<usize>,   // Length of the decoded payload, in bytes
+    pub max_parameters: Option<usize>,    // Number of parameters, base64 marker not included
+    pub max_parameter_len: Option<usize>, // Length of any single parameter, name included
+    pub ignore_base64_whitespace: bool, // Strips whitespace from base64 payloads, like browsers do
+    #[cfg(feature = "compression")]
+    pub decompress: bool, // Undoes content-encoding, max_decoded_len applying to the decompressed payload too
+}
+
+// How to_string_with_options() lays out the URL
+#[derive(Clone, Copy, Debug, Default)]
+pub struct StringOptions {
+    pub wrap: Option<usize>, // Breaks base64 payloads into lines of given width (76 for MIME), using CRLF
+}
+
+fn check(value: usize, limit: Option<usize>) -> Result<(), DataUrlParseError> {
+    match limit {
+        Some(limit) if value > limit => Err(DataUrlParseError::LimitExceeded),
+        _ => Ok(()),
+    }
+}
+
+impl ParseOptions {
+    pub(crate) fn check_input(&self, input: &str) -> Result<(), DataUrlParseError> {
+        check(input.len(), self.max_input_len)
+    }
+
+    pub(crate) fn check_meta_data(&self, meta_data: &str) -> Result<(), DataUrlParseError> {
+        let mut count: usize = 0;
+
+        // The first item is the media type, which isn't a parameter
+        for item in meta_data.split(';').skip(1) {
+            if !item.trim().eq_ignore_ascii_case("base64") {
+                count += 1;
+                check(count, self.max_parameters)?;
+                check(item.len(), self.max_parameter_len)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn check_decoded_len(&self, decoded_len: usize) -> Result<(), DataUrlParseError> {
+        check(decoded_len, self.max_decoded_len)
+    }
+}
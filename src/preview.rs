@@ -0,0 +1,206 @@
+use dataurl::DataUrl;
+use std::fmt::Write as _;
+
+const INDENT: &str = "  ";
+
+fn is_json(media_type: &str) -> bool {
+    media_type == "application/json" || media_type.ends_with("+json")
+}
+
+fn is_xml(media_type: &str) -> bool {
+    media_type == "application/xml" || media_type == "text/xml" || media_type.ends_with("+xml")
+}
+
+// Same layout as xxd: offset, 16 bytes in groups of two, then the printable ones
+pub fn hexdump(data: &[u8]) -> String {
+    let mut output: String = String::new();
+
+    for (index, chunk) in data.chunks(16).enumerate() {
+        let _ = write!(output, "{:08x}:", index * 16);
+        for (i, byte) in chunk.iter().enumerate() {
+            if i % 2 == 0 {
+                output.push(' ');
+            }
+            let _ = write!(output, "{:02x}", byte);
+        }
+        let width: usize = chunk.len() * 2 + chunk.len().div_ceil(2);
+        output.push_str(&" ".repeat(40 - width + 2));
+        for byte in chunk {
+            output.push(if byte.is_ascii_graphic() || *byte == b' ' {
+                *byte as char
+            } else {
+                '.'
+            });
+        }
+        output.push('\n');
+    }
+
+    output
+}
+
+// Re-indents JSON without validating it, whitespace outside of strings gets dropped
+pub fn pretty_json(text: &str) -> String {
+    let mut output: String = String::with_capacity(text.len() * 2);
+    let mut depth: usize = 0;
+    let mut in_string: bool = false;
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if in_string {
+            output.push(c);
+            if c == '\\' {
+                if let Some(escaped) = chars.next() {
+                    output.push(escaped);
+                }
+            } else if c == '"' {
+                in_string = false;
+            }
+            continue;
+        }
+
+        match c {
+            '"' => {
+                in_string = true;
+                output.push(c);
+            }
+            '{' | '[' => {
+                output.push(c);
+                while chars.peek().is_some_and(|c| c.is_whitespace()) {
+                    chars.next();
+                }
+                // Empty objects and arrays stay on one line
+                if chars.peek() == Some(&if c == '{' { '}' } else { ']' }) {
+                    output.push(chars.next().unwrap());
+                } else {
+                    depth += 1;
+                    output.push('\n');
+                    output.push_str(&INDENT.repeat(depth));
+                }
+            }
+            '}' | ']' => {
+                depth = depth.saturating_sub(1);
+                output.push('\n');
+                output.push_str(&INDENT.repeat(depth));
+                output.push(c);
+            }
+            ',' => {
+                output.push(c);
+                output.push('\n');
+                output.push_str(&INDENT.repeat(depth));
+            }
+            ':' => output.push_str(": "),
+            c if c.is_whitespace() => {}
+            c => output.push(c),
+        }
+    }
+
+    output.push('\n');
+    output
+}
+
+// Splits markup into tags (comments, CDATA sections and the like included) and text in between
+fn xml_tokens(text: &str) -> Vec<&str> {
+    let mut tokens: Vec<&str> = Vec::new();
+    let mut rest: &str = text;
+
+    while !rest.is_empty() {
+        let end: usize = if rest.starts_with("<!--") {
+            rest.find("-->").map_or(rest.len(), |i| i + 3)
+        } else if rest.starts_with("<![CDATA[") {
+            rest.find("]]>").map_or(rest.len(), |i| i + 3)
+        } else if rest.starts_with('<') {
+            // Attribute values may contain >
+            let mut quote: Option<char> = None;
+            rest.char_indices()
+                .find(|&(_, c)| {
+                    match quote {
+                        Some(q) if c == q => quote = None,
+                        Some(_) => {}
+                        None if c == '"' || c == '\'' => quote = Some(c),
+                        None => return c == '>',
+                    }
+                    false
+                })
+                .map_or(rest.len(), |(i, _)| i + 1)
+        } else {
+            rest.find('<').unwrap_or(rest.len())
+        };
+        tokens.push(&rest[..end]);
+        rest = &rest[end..];
+    }
+
+    tokens
+}
+
+fn is_opening_tag(token: &str) -> bool {
+    token.starts_with('<')
+        && !token.starts_with("</")
+        && !token.starts_with("<!")
+        && !token.starts_with("<?")
+        && !token.ends_with("/>")
+}
+
+// One element per line, unless all it contains is a bit of text
+pub fn pretty_xml(text: &str) -> String {
+    let tokens: Vec<&str> = xml_tokens(text)
+        .into_iter()
+        .map(|token| token.trim())
+        .filter(|token| !token.is_empty())
+        .collect();
+    let mut output: String = String::with_capacity(text.len() * 2);
+    let mut depth: usize = 0;
+    let mut i: usize = 0;
+
+    while i < tokens.len() {
+        let token: &str = tokens[i];
+
+        if token.starts_with("</") {
+            depth = depth.saturating_sub(1);
+        }
+        output.push_str(&INDENT.repeat(depth));
+        output.push_str(token);
+
+        if is_opening_tag(token) {
+            match (tokens.get(i + 1), tokens.get(i + 2)) {
+                (Some(next), _) if next.starts_with("</") => {
+                    output.push_str(next);
+                    i += 1;
+                }
+                (Some(text), Some(next)) if !text.starts_with('<') && next.starts_with("</") => {
+                    output.push_str(text);
+                    output.push_str(next);
+                    i += 2;
+                }
+                _ => depth += 1,
+            }
+        }
+
+        output.push('\n');
+        i += 1;
+    }
+
+    output
+}
+
+// What to show instead of the payload itself when it's meant for human eyes
+pub fn preview(data_url: &DataUrl) -> String {
+    let media_type: &str = data_url.media_type();
+
+    if is_json(media_type) {
+        pretty_json(&data_url.text())
+    } else if is_xml(media_type) {
+        pretty_xml(&data_url.text())
+    } else if let Some((width, height)) = data_url.image_dimensions() {
+        format!(
+            "{}, {}x{}, {} bytes\n",
+            media_type,
+            width,
+            height,
+            data_url.data().len()
+        )
+    } else if data_url.is_binary() {
+        hexdump(data_url.data())
+    } else {
+        data_url.text()
+    }
+}
@@ -0,0 +1,167 @@
+use pyo3::create_exception;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+
+use crate::DataUrl;
+
+// DataUrlParseError is a ValueError, each variant of the Rust enum gets its own subclass
+create_exception!(dataurl, DataUrlParseError, PyValueError);
+create_exception!(dataurl, UrlParseError, DataUrlParseError);
+create_exception!(dataurl, MalformedDataUrlError, DataUrlParseError);
+create_exception!(dataurl, Base64DecodeError, DataUrlParseError);
+create_exception!(dataurl, LimitExceeded, DataUrlParseError);
+create_exception!(dataurl, InvalidMediaType, DataUrlParseError);
+create_exception!(dataurl, InvalidParameter, DataUrlParseError);
+create_exception!(dataurl, InvalidCharacter, DataUrlParseError);
+create_exception!(dataurl, DecompressionError, DataUrlParseError);
+
+fn parse_error_to_py(err: crate::DataUrlParseError) -> PyErr {
+    match err {
+        crate::DataUrlParseError::UrlParseError => UrlParseError::new_err("unable to parse URL"),
+        crate::DataUrlParseError::MalformedDataUrlError => {
+            MalformedDataUrlError::new_err("malformed data URL")
+        }
+        crate::DataUrlParseError::Base64DecodeError => {
+            Base64DecodeError::new_err("unable to decode base64")
+        }
+        crate::DataUrlParseError::LimitExceeded => LimitExceeded::new_err("limit exceeded"),
+        crate::DataUrlParseError::InvalidMediaType => {
+            InvalidMediaType::new_err("invalid media type")
+        }
+        crate::DataUrlParseError::InvalidParameter => {
+            InvalidParameter::new_err("invalid parameter")
+        }
+        crate::DataUrlParseError::InvalidCharacter => {
+            InvalidCharacter::new_err("invalid character")
+        }
+        crate::DataUrlParseError::DecompressionError => {
+            DecompressionError::new_err("unable to decompress data")
+        }
+    }
+}
+
+#[pyclass(name = "DataUrl", module = "dataurl")]
+pub struct PyDataUrl {
+    inner: DataUrl,
+}
+
+#[pymethods]
+impl PyDataUrl {
+    #[new]
+    fn new() -> PyDataUrl {
+        PyDataUrl {
+            inner: DataUrl::new(),
+        }
+    }
+
+    #[staticmethod]
+    fn parse(input: &str) -> PyResult<PyDataUrl> {
+        DataUrl::parse(input)
+            .map(|inner| PyDataUrl { inner })
+            .map_err(parse_error_to_py)
+    }
+
+    #[getter]
+    fn media_type(&self) -> &str {
+        self.inner.media_type()
+    }
+
+    #[setter]
+    fn set_media_type(&mut self, media_type: Option<String>) -> PyResult<()> {
+        if self.inner.set_media_type(media_type) {
+            Ok(())
+        } else {
+            Err(PyValueError::new_err("invalid media type"))
+        }
+    }
+
+    #[getter]
+    fn charset(&self) -> &str {
+        self.inner.charset()
+    }
+
+    #[setter]
+    fn set_charset(&mut self, charset: Option<String>) -> PyResult<()> {
+        if self.inner.set_charset(charset) {
+            Ok(())
+        } else {
+            Err(PyValueError::new_err("invalid charset"))
+        }
+    }
+
+    #[getter]
+    fn base64(&self) -> bool {
+        self.inner.is_base64_encoded()
+    }
+
+    #[setter]
+    fn set_base64(&mut self, base64: bool) {
+        self.inner.set_is_base64_encoded(base64);
+    }
+
+    #[getter]
+    fn data<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        PyBytes::new(py, self.inner.data())
+    }
+
+    #[setter]
+    fn set_data(&mut self, data: &[u8]) {
+        self.inner.set_data(data);
+    }
+
+    #[getter]
+    fn fragment(&self) -> Option<String> {
+        self.inner.fragment()
+    }
+
+    #[setter]
+    fn set_fragment(&mut self, fragment: Option<String>) {
+        self.inner.set_fragment(fragment);
+    }
+
+    #[getter]
+    fn is_binary(&self) -> bool {
+        self.inner.is_binary()
+    }
+
+    fn text(&self) -> String {
+        self.inner.text()
+    }
+
+    fn __str__(&self) -> String {
+        self.inner.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("DataUrl({:?})", self.inner.to_string())
+    }
+}
+
+#[pyfunction]
+fn parse(input: &str) -> PyResult<PyDataUrl> {
+    PyDataUrl::parse(input)
+}
+
+#[pymodule]
+fn dataurl(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyDataUrl>()?;
+    m.add_function(wrap_pyfunction!(parse, m)?)?;
+    m.add("DataUrlParseError", m.py().get_type::<DataUrlParseError>())?;
+    m.add("UrlParseError", m.py().get_type::<UrlParseError>())?;
+    m.add(
+        "MalformedDataUrlError",
+        m.py().get_type::<MalformedDataUrlError>(),
+    )?;
+    m.add("Base64DecodeError", m.py().get_type::<Base64DecodeError>())?;
+    m.add("LimitExceeded", m.py().get_type::<LimitExceeded>())?;
+    m.add("InvalidMediaType", m.py().get_type::<InvalidMediaType>())?;
+    m.add("InvalidParameter", m.py().get_type::<InvalidParameter>())?;
+    m.add("InvalidCharacter", m.py().get_type::<InvalidCharacter>())?;
+    m.add(
+        "DecompressionError",
+        m.py().get_type::<DecompressionError>(),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,128 @@
+#[cfg(feature = "image")]
+use image::{
+    codecs::{
+        jpeg::JpegEncoder,
+        png::{CompressionType, FilterType as PngFilterType, PngEncoder},
+        webp::WebPEncoder,
+    },
+    imageops::FilterType,
+    DynamicImage, ImageFormat as Format,
+};
+#[cfg(feature = "image")]
+use std::io;
+
+#[cfg(feature = "image")]
+use crate::DataUrl;
+
+// Formats images can be re-encoded into
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ImageFormat {
+    Jpeg,
+    Png,
+    Webp,
+}
+
+impl ImageFormat {
+    pub fn from_name(name: &str) -> Option<ImageFormat> {
+        match name.trim().to_lowercase().as_str() {
+            "jpeg" | "jpg" => Some(ImageFormat::Jpeg),
+            "png" => Some(ImageFormat::Png),
+            "webp" => Some(ImageFormat::Webp),
+            _ => None,
+        }
+    }
+
+    pub fn media_type(&self) -> &'static str {
+        match self {
+            ImageFormat::Jpeg => "image/jpeg",
+            ImageFormat::Png => "image/png",
+            ImageFormat::Webp => "image/webp",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct ImageOptions {
+    pub max_dimension: Option<u32>, // Larger images get scaled down, keeping their aspect ratio
+    pub format: Option<ImageFormat>, // None keeps the original format, if possible
+    pub quality: u8, // 1 to 100, only affects JPEG (PNG and WebP are always lossless)
+}
+
+impl Default for ImageOptions {
+    fn default() -> Self {
+        ImageOptions {
+            max_dimension: None,
+            format: None,
+            quality: 80,
+        }
+    }
+}
+
+#[cfg(feature = "image")]
+fn encode(image: &DynamicImage, format: ImageFormat, quality: u8) -> image::ImageResult<Vec<u8>> {
+    let mut output: Vec<u8> = Vec::new();
+
+    match format {
+        ImageFormat::Jpeg => {
+            // JPEG has no alpha channel
+            let encoder = JpegEncoder::new_with_quality(&mut output, quality.clamp(1, 100));
+            DynamicImage::ImageRgb8(image.to_rgb8()).write_with_encoder(encoder)?;
+        }
+        ImageFormat::Png => {
+            let encoder = PngEncoder::new_with_quality(
+                &mut output,
+                CompressionType::Best,
+                PngFilterType::Adaptive,
+            );
+            image.write_with_encoder(encoder)?;
+        }
+        ImageFormat::Webp => {
+            let encoder = WebPEncoder::new_lossless(&mut output);
+            DynamicImage::ImageRgba8(image.to_rgba8()).write_with_encoder(encoder)?;
+        }
+    }
+
+    Ok(output)
+}
+
+#[cfg(feature = "image")]
+impl DataUrl {
+    pub fn reencode_image(&mut self, options: &ImageOptions) -> io::Result<()> {
+        #[cfg(feature = "compression")]
+        self.decompress()?;
+
+        let original_format: Format = image::guess_format(self.data())
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        let mut image: DynamicImage =
+            image::load_from_memory_with_format(self.data(), original_format)
+                .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+
+        let needs_resizing: bool = options
+            .max_dimension
+            .is_some_and(|max| image.width() > max || image.height() > max);
+        if needs_resizing {
+            let max: u32 = options.max_dimension.unwrap().max(1);
+            image = image.resize(max, max, FilterType::Lanczos3);
+        }
+
+        let format: ImageFormat = match options.format {
+            Some(format) => format,
+            None if !needs_resizing => return Ok(()),
+            // Formats which can't be written (GIF, etc) turn into PNG
+            None => match original_format {
+                Format::Jpeg => ImageFormat::Jpeg,
+                Format::WebP => ImageFormat::Webp,
+                _ => ImageFormat::Png,
+            },
+        };
+
+        let encoded: Vec<u8> = encode(&image, format, options.quality)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        self.set_data_owned(encoded);
+        self.set_media_type(Some(format.media_type().to_string()));
+        self.set_charset(None);
+        self.set_is_base64_encoded(true);
+
+        Ok(())
+    }
+}
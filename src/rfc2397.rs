@@ -0,0 +1,142 @@
+use alloc::vec::Vec;
+use encoding_rs::Encoding;
+use percent_encoding::percent_decode_str;
+
+use crate::DataUrlParseError;
+
+// https://datatracker.ietf.org/doc/html/rfc2045#section-5.1
+fn is_token(s: &str) -> bool {
+    !s.is_empty()
+        && s.bytes()
+            .all(|b| b.is_ascii_graphic() && !b"()<>@,;:\\\"/[]?=".contains(&b))
+}
+
+// https://datatracker.ietf.org/doc/html/rfc822#section-3.3
+fn is_quoted_string(s: &str) -> bool {
+    let inner: &str = match s.strip_prefix('"').and_then(|s| s.strip_suffix('"')) {
+        Some(inner) => inner,
+        None => return false,
+    };
+    let mut chars = inner.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' if chars.next().is_none() => return false,
+            '"' | '\r' => return false,
+            c if !c.is_ascii() => return false,
+            _ => {}
+        }
+    }
+
+    true
+}
+
+// https://datatracker.ietf.org/doc/html/rfc2396#section-2
+fn validate_urlchars(s: &str) -> Result<(), DataUrlParseError> {
+    let bytes: &[u8] = s.as_bytes();
+    let mut i: usize = 0;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            b'%' => {
+                // Escaped octets are exactly two hexadecimal digits long
+                if i + 2 >= bytes.len()
+                    || !bytes[i + 1].is_ascii_hexdigit()
+                    || !bytes[i + 2].is_ascii_hexdigit()
+                {
+                    return Err(DataUrlParseError::InvalidCharacter);
+                }
+                i += 2;
+            }
+            b if b.is_ascii_alphanumeric() || b"-_.!~*'();/?:@&=+$,".contains(&b) => {}
+            _ => return Err(DataUrlParseError::InvalidCharacter),
+        }
+        i += 1;
+    }
+
+    Ok(())
+}
+
+// Base64 marker aside, RFC 2045's alphabet with padding only at the very end
+fn validate_base64(data: &str) -> Result<(), DataUrlParseError> {
+    let data: Vec<u8> = percent_decode_str(data).collect();
+    let padding: usize = data.iter().rev().take_while(|b| **b == b'=').count();
+
+    if !data.len().is_multiple_of(4)
+        || padding > 2
+        || !data[..data.len() - padding]
+            .iter()
+            .all(|b| b.is_ascii_alphanumeric() || *b == b'+' || *b == b'/')
+    {
+        return Err(DataUrlParseError::Base64DecodeError);
+    }
+
+    Ok(())
+}
+
+//   dataurl    := "data:" [ mediatype ] [ ";base64" ] "," data
+//   mediatype  := [ type "/" subtype ] *( ";" parameter )
+//   data       := *urlchar
+//   parameter  := attribute "=" value
+pub(crate) fn validate(input: &str) -> Result<(), DataUrlParseError> {
+    let (scheme, rest): (&str, &str) = input
+        .split_once(':')
+        .ok_or(DataUrlParseError::UrlParseError)?;
+    if !scheme.eq_ignore_ascii_case("data") {
+        return Err(DataUrlParseError::UrlParseError);
+    }
+
+    let (rest, fragment): (&str, Option<&str>) = match rest.split_once('#') {
+        Some((rest, fragment)) => (rest, Some(fragment)),
+        None => (rest, None),
+    };
+    let (meta_data, data): (&str, &str) = rest
+        .split_once(',')
+        .ok_or(DataUrlParseError::MalformedDataUrlError)?;
+    validate_urlchars(meta_data)?;
+    validate_urlchars(data)?;
+    if let Some(fragment) = fragment {
+        validate_urlchars(fragment)?;
+    }
+
+    let mut items: Vec<&str> = meta_data.split(';').collect();
+    let media_type: &str = items.remove(0);
+    if !media_type.is_empty() {
+        match media_type.split_once('/') {
+            Some((t, subtype)) if is_token(t) && is_token(subtype) => {}
+            _ => return Err(DataUrlParseError::InvalidMediaType),
+        }
+    }
+
+    let is_base64_encoded: bool = items
+        .last()
+        .map(|item| item.eq_ignore_ascii_case("base64"))
+        .unwrap_or(false);
+    if is_base64_encoded {
+        items.pop();
+    }
+
+    for item in items {
+        let (attribute, value): (&str, &str) = item
+            .split_once('=')
+            .ok_or(DataUrlParseError::InvalidParameter)?;
+        let value = percent_decode_str(value)
+            .decode_utf8()
+            .map_err(|_| DataUrlParseError::InvalidParameter)?;
+        if !is_token(attribute) || !(is_token(&value) || is_quoted_string(&value)) {
+            return Err(DataUrlParseError::InvalidParameter);
+        }
+        // Unknown charsets would otherwise get silently dropped
+        if attribute.eq_ignore_ascii_case("charset")
+            && Encoding::for_label_no_replacement(value.as_bytes()).is_none()
+        {
+            return Err(DataUrlParseError::InvalidParameter);
+        }
+    }
+
+    if is_base64_encoded {
+        validate_base64(data)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,66 @@
+use alloc::string::String;
+
+// Magic byte signatures, checked in order; the first match wins
+const SIGNATURES: &[(&[u8], &str)] = &[
+    (b"\x89PNG\r\n\x1a\n", "image/png"),
+    (b"\xff\xd8\xff", "image/jpeg"),
+    (b"GIF87a", "image/gif"),
+    (b"GIF89a", "image/gif"),
+    (b"BM", "image/bmp"),
+    (b"\x00\x00\x01\x00", "image/x-icon"),
+    (b"%PDF-", "application/pdf"),
+    (b"PK\x03\x04", "application/zip"),
+    (b"\x1f\x8b", "application/gzip"),
+    (b"wOFF", "font/woff"),
+    (b"wOF2", "font/woff2"),
+    (b"\x00\x01\x00\x00", "font/ttf"),
+    (b"OTTO", "font/otf"),
+    (b"\x00asm", "application/wasm"),
+    (b"OggS", "audio/ogg"),
+    (b"fLaC", "audio/flac"),
+    (b"ID3", "audio/mpeg"),
+    (b"\x1a\x45\xdf\xa3", "video/webm"),
+];
+
+pub(crate) fn sniff_media_type(data: &[u8]) -> Option<&'static str> {
+    for (signature, media_type) in SIGNATURES {
+        if data.starts_with(signature) {
+            return Some(media_type);
+        }
+    }
+
+    // RIFF containers carry their actual format at offset 8
+    if data.len() >= 12 && data.starts_with(b"RIFF") {
+        match &data[8..12] {
+            b"WEBP" => return Some("image/webp"),
+            b"WAVE" => return Some("audio/wav"),
+            b"AVI " => return Some("video/x-msvideo"),
+            _ => {}
+        }
+    }
+
+    // ISO base media files (MP4, AVIF, etc) have their brand at offset 4
+    if data.len() >= 12 && &data[4..8] == b"ftyp" {
+        match &data[8..12] {
+            b"avif" => return Some("image/avif"),
+            b"qt  " => return Some("video/quicktime"),
+            _ => return Some("video/mp4"),
+        }
+    }
+
+    // Markup is only recognized if it's valid UTF-8 text
+    if let Ok(text) = core::str::from_utf8(data) {
+        let text = text.trim_start_matches('\u{feff}').trim_start();
+        let head: String = text.chars().take(256).collect::<String>().to_lowercase();
+
+        if head.starts_with("<svg") || (head.starts_with("<?xml") && head.contains("<svg")) {
+            return Some("image/svg+xml");
+        } else if head.starts_with("<!doctype html") || head.starts_with("<html") {
+            return Some("text/html");
+        } else if head.starts_with("<?xml") {
+            return Some("application/xml");
+        }
+    }
+
+    None
+}
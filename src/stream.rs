@@ -0,0 +1,380 @@
+use percent_encoding::percent_decode_str;
+use std::io;
+use std::iter;
+use std::mem;
+use std::str;
+
+use crate::{codec, url, DataUrl, DataUrlParseError, ParseMode, ParseOptions};
+
+// How much the async adaptors read or write at a time
+pub(crate) const CHUNK_LEN: usize = 8 * 1024;
+
+const REPLACEMENT_CHARACTER: &str = "\u{FFFD}";
+
+// Fed the input one chunk at a time, decoding the payload as it comes in rather than once all of it is in memory;
+// ends up with the same result as parse_with_options() would on the input converted into a string
+pub(crate) struct StreamingParser<'a> {
+    options: &'a ParseOptions,
+    input_len: usize,
+    utf8_tail: Vec<u8>,        // Incomplete UTF-8 sequence ending the last chunk
+    text: Vec<u8>,             // Input up to the comma, or all of it if buffering
+    buffering: bool,           // Parsing it all in one go, as strict modes have to
+    data_url: Option<DataUrl>, // Meta data, once parsed
+    percent: Vec<u8>,          // Start of an escape, waiting for the rest of it
+    spaces: Vec<u8>,           // Trimmed off unless something follows them
+    base64: Vec<u8>,           // Base64 not decoded yet
+    base64_len: usize,         // Base64 characters so far, not counting padding
+    alphabets: (bool, bool),   // Standard and URL-safe base64 characters seen
+    invalid_base64: bool,
+    data: Vec<u8>,
+    fragment: Option<Vec<u8>>,
+}
+
+impl<'a> StreamingParser<'a> {
+    pub(crate) fn new(options: &'a ParseOptions) -> StreamingParser<'a> {
+        StreamingParser {
+            options,
+            input_len: 0,
+            utf8_tail: Vec::new(),
+            text: Vec::new(),
+            buffering: options.mode != ParseMode::Lenient,
+            data_url: None,
+            percent: Vec::new(),
+            spaces: Vec::new(),
+            base64: Vec::new(),
+            base64_len: 0,
+            alphabets: (false, false),
+            invalid_base64: false,
+            data: Vec::new(),
+            fragment: None,
+        }
+    }
+
+    // Invalid UTF-8 gets replaced the way String::from_utf8_lossy() does it, sequences split between chunks included
+    pub(crate) fn feed(&mut self, chunk: &[u8]) -> Result<(), DataUrlParseError> {
+        let mut input: Vec<u8> = mem::take(&mut self.utf8_tail);
+        input.extend_from_slice(chunk);
+        let mut rest: &[u8] = &input;
+
+        loop {
+            match str::from_utf8(rest) {
+                Ok(valid) => return self.push_text(valid.as_bytes()),
+                Err(err) => {
+                    let (valid, invalid) = rest.split_at(err.valid_up_to());
+                    self.push_text(valid)?;
+                    match err.error_len() {
+                        Some(len) => {
+                            self.push_text(REPLACEMENT_CHARACTER.as_bytes())?;
+                            rest = &invalid[len..];
+                        }
+                        None => {
+                            self.utf8_tail = invalid.to_vec();
+                            return Ok(());
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    pub(crate) fn finish(mut self) -> Result<DataUrl, DataUrlParseError> {
+        if !self.utf8_tail.is_empty() {
+            self.push_text(REPLACEMENT_CHARACTER.as_bytes())?;
+        }
+
+        if self.data_url.is_none() {
+            let input: String = String::from_utf8(mem::take(&mut self.text)).unwrap();
+            return DataUrl::parse_with_options(&input, self.options);
+        }
+
+        // Whatever is left of an escape that never got completed stays as is
+        for b in mem::take(&mut self.percent) {
+            self.push_decoded(b)?;
+        }
+        let mut data_url: DataUrl = self.data_url.take().unwrap();
+        if data_url.is_base64_encoded {
+            if !self.invalid_base64 {
+                self.decode_base64(true);
+            }
+            if self.invalid_base64 {
+                return Err(DataUrlParseError::Base64DecodeError);
+            }
+        }
+        data_url.set_data_owned(mem::take(&mut self.data));
+        data_url.fragment = self.fragment.take().map(|f| {
+            let f: String = String::from_utf8(f).unwrap();
+            let f: &str = f.trim_end_matches(|c: char| c <= ' ');
+            percent_decode_str(&url::encode_fragment(f))
+                .decode_utf8_lossy()
+                .to_string()
+        });
+
+        #[cfg(feature = "compression")]
+        if self.options.decompress {
+            data_url.decompress_with_options(self.options)?;
+        }
+
+        Ok(data_url)
+    }
+
+    fn push_text(&mut self, text: &[u8]) -> Result<(), DataUrlParseError> {
+        self.input_len += text.len();
+        if let Some(max_input_len) = self.options.max_input_len {
+            if self.input_len > max_input_len {
+                return Err(DataUrlParseError::LimitExceeded);
+            }
+        }
+
+        for (i, &b) in text.iter().enumerate() {
+            if self.buffering {
+                self.text.extend_from_slice(&text[i..]);
+                break;
+            }
+
+            if self.data_url.is_none() {
+                self.push_meta_data(b)?;
+            } else if !matches!(b, b'\t' | b'\n' | b'\r') {
+                self.push_payload(b)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn push_meta_data(&mut self, b: u8) -> Result<(), DataUrlParseError> {
+        self.text.push(b);
+
+        match b {
+            b',' => {
+                // Parsed the same way as a URL with an empty payload, limits on the payload applying later
+                let options: ParseOptions = ParseOptions {
+                    max_input_len: None,
+                    max_decoded_len: None,
+                    #[cfg(feature = "compression")]
+                    decompress: false,
+                    ..self.options.clone()
+                };
+                let meta_data: String = String::from_utf8(mem::take(&mut self.text)).unwrap();
+                self.data_url = Some(DataUrl::parse_with_options(&meta_data, &options)?);
+            }
+            // Can't tell where the payload starts with a query or fragment before the comma
+            b'#' | b'?' => self.buffering = true,
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    fn push_payload(&mut self, b: u8) -> Result<(), DataUrlParseError> {
+        if let Some(fragment) = &mut self.fragment {
+            fragment.push(b);
+            return Ok(());
+        }
+
+        if b <= b' ' {
+            self.spaces.push(b);
+            return Ok(());
+        }
+        for space in mem::take(&mut self.spaces) {
+            self.percent_decode(space)?;
+        }
+
+        if b == b'#' {
+            self.fragment = Some(Vec::new());
+            Ok(())
+        } else {
+            // Anything after a question mark is the query, which ends up in the payload along with the question mark
+            self.percent_decode(b)
+        }
+    }
+
+    fn percent_decode(&mut self, b: u8) -> Result<(), DataUrlParseError> {
+        match self.percent.len() {
+            0 if b == b'%' => {
+                self.percent.push(b);
+                Ok(())
+            }
+            0 => self.push_decoded(b),
+            1 if b.is_ascii_hexdigit() => {
+                self.percent.push(b);
+                Ok(())
+            }
+            _ if b.is_ascii_hexdigit() => {
+                let high: u8 = (self.percent[1] as char).to_digit(16).unwrap() as u8;
+                let low: u8 = (b as char).to_digit(16).unwrap() as u8;
+                self.percent.clear();
+                self.push_decoded(high << 4 | low)
+            }
+            _ => {
+                for p in mem::take(&mut self.percent) {
+                    self.push_decoded(p)?;
+                }
+                self.percent_decode(b)
+            }
+        }
+    }
+
+    fn push_decoded(&mut self, b: u8) -> Result<(), DataUrlParseError> {
+        if !self.data_url.as_ref().unwrap().is_base64_encoded {
+            self.data.push(b);
+            return self.options.check_decoded_len(self.data.len());
+        }
+
+        if self.options.ignore_base64_whitespace && b.is_ascii_whitespace() {
+            return Ok(());
+        }
+        if b != b'=' && !b.is_ascii_whitespace() {
+            self.base64_len += 1;
+            self.options.check_decoded_len(self.base64_len * 3 / 4)?;
+        }
+        // The limit still applies to the rest of a payload which can't be decoded
+        if !self.invalid_base64 {
+            self.base64.push(b);
+            if self.base64.len() >= CHUNK_LEN + 4 {
+                self.decode_base64(false);
+            }
+        }
+
+        Ok(())
+    }
+
+    // Whole groups of four only, some of the base64 always staying behind until the end, where padding may be
+    fn decode_base64(&mut self, at_end: bool) {
+        let len: usize = if at_end {
+            self.base64.len()
+        } else {
+            (self.base64.len() - 1) / 4 * 4
+        };
+        let encoded: &[u8] = &self.base64[..len];
+
+        self.alphabets.0 |= encoded.iter().any(|b| matches!(b, b'+' | b'/'));
+        self.alphabets.1 |= encoded.iter().any(|b| matches!(b, b'-' | b'_'));
+        let decoded: Option<Vec<u8>> =
+            if self.alphabets == (true, true) || (!at_end && encoded.contains(&b'=')) {
+                None
+            } else {
+                codec::base64_decode(encoded)
+            };
+
+        match decoded {
+            Some(decoded) => {
+                self.data.extend_from_slice(&decoded);
+                self.base64.drain(..len);
+            }
+            None => {
+                self.invalid_base64 = true;
+                self.base64 = Vec::new();
+            }
+        }
+    }
+}
+
+// Same output as to_string(), one piece at a time, the payload getting encoded chunk by chunk
+pub(crate) fn to_string_chunks(data_url: &DataUrl) -> Box<dyn Iterator<Item = String> + '_> {
+    if data_url.original.is_some() {
+        return Box::new(iter::once(data_url.to_string()));
+    }
+
+    // Multiples of 3 bytes, for base64 padding to only ever end up at the very end
+    let payload = data_url.data.chunks(CHUNK_LEN / 4 * 3).map(move |chunk| {
+        if data_url.is_base64_encoded {
+            codec::base64_encode(chunk)
+        } else {
+            codec::percent_encode(chunk)
+        }
+    });
+
+    Box::new(
+        iter::once(format!("data:{},", data_url.meta_data_to_string(false)))
+            .chain(payload)
+            .chain(data_url.fragment_to_string().map(|f| format!("#{}", f))),
+    )
+}
+
+pub(crate) fn invalid_data(err: DataUrlParseError) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, err)
+}
+
+// Both async modules get the same functions, only the traits those are generic over coming from different crates
+macro_rules! async_io {
+    () => {
+        use std::io;
+
+        use crate::stream::{invalid_data, to_string_chunks, StreamingParser, CHUNK_LEN};
+        use crate::{DataUrl, DataUrlParseError, ParseOptions};
+
+        async fn read_data<R: AsyncRead + Unpin>(
+            mut reader: R,
+            max_len: Option<usize>,
+        ) -> io::Result<Vec<u8>> {
+            let mut data: Vec<u8> = Vec::new();
+            let mut buffer: Vec<u8> = vec![0; CHUNK_LEN];
+
+            loop {
+                let len: usize = reader.read(&mut buffer).await?;
+                if len == 0 {
+                    return Ok(data);
+                }
+                data.extend_from_slice(&buffer[..len]);
+                if max_len.map_or(false, |max_len| data.len() > max_len) {
+                    return Err(invalid_data(DataUrlParseError::LimitExceeded));
+                }
+            }
+        }
+
+        pub async fn from_reader<R: AsyncRead + Unpin>(reader: R) -> io::Result<DataUrl> {
+            Ok(DataUrl::from_sniffed_bytes(read_data(reader, None).await?))
+        }
+
+        // Stops reading as soon as there's more than max_len bytes, failing with InvalidData
+        pub async fn from_reader_with_limit<R: AsyncRead + Unpin>(
+            reader: R,
+            max_len: usize,
+        ) -> io::Result<DataUrl> {
+            Ok(DataUrl::from_sniffed_bytes(
+                read_data(reader, Some(max_len)).await?,
+            ))
+        }
+
+        pub async fn parse_reader<R: AsyncRead + Unpin>(reader: R) -> io::Result<DataUrl> {
+            parse_reader_with_options(reader, &ParseOptions::default()).await
+        }
+
+        // Limits get enforced while reading, which stops at the first chunk going over one of them
+        pub async fn parse_reader_with_options<R: AsyncRead + Unpin>(
+            mut reader: R,
+            options: &ParseOptions,
+        ) -> io::Result<DataUrl> {
+            let mut parser: StreamingParser = StreamingParser::new(options);
+            let mut buffer: Vec<u8> = vec![0; CHUNK_LEN];
+
+            loop {
+                let len: usize = reader.read(&mut buffer).await?;
+                if len == 0 {
+                    return parser.finish().map_err(invalid_data);
+                }
+                parser.feed(&buffer[..len]).map_err(invalid_data)?;
+            }
+        }
+
+        pub async fn write_to<W: AsyncWrite + Unpin>(
+            data_url: &DataUrl,
+            mut writer: W,
+        ) -> io::Result<()> {
+            for chunk in to_string_chunks(data_url) {
+                writer.write_all(chunk.as_bytes()).await?;
+            }
+            writer.flush().await
+        }
+
+        pub async fn write_data_to<W: AsyncWrite + Unpin>(
+            data_url: &DataUrl,
+            mut writer: W,
+        ) -> io::Result<()> {
+            writer.write_all(data_url.data()).await?;
+            writer.flush().await
+        }
+    };
+}
+
+pub(crate) use async_io;
@@ -0,0 +1,3 @@
+use ::tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+crate::stream::async_io!();
@@ -0,0 +1,86 @@
+use alloc::borrow::Cow;
+use alloc::string::{String, ToString};
+use percent_encoding::{utf8_percent_encode, AsciiSet, CONTROLS};
+
+use crate::DataUrlParseError;
+
+// https://url.spec.whatwg.org/#fragment-percent-encode-set
+const FRAGMENT: &AsciiSet = &CONTROLS.add(b' ').add(b'"').add(b'<').add(b'>').add(b'`');
+
+// Data URLs have opaque paths, so only a tiny subset of the URL parser is needed
+pub(crate) struct Url<'a> {
+    path: Cow<'a, str>,
+    query: Option<Cow<'a, str>>,
+    fragment: Option<String>,
+}
+
+impl<'a> Url<'a> {
+    pub(crate) fn parse(input_str: &'a str) -> Result<Url<'a>, DataUrlParseError> {
+        // Leading and trailing C0 control or space, as well as all tabs and newlines get removed
+        let input: &str = input_str.trim_matches(|c: char| c <= ' ');
+
+        // Payloads can be huge, they only get copied if there's something to remove
+        if input.bytes().any(|b| matches!(b, b'\t' | b'\n' | b'\r')) {
+            let input: String = input
+                .chars()
+                .filter(|c| !matches!(c, '\t' | '\n' | '\r'))
+                .collect();
+            Url::parse_stripped(&input).map(Url::into_owned)
+        } else {
+            Url::parse_stripped(input)
+        }
+    }
+
+    fn parse_stripped(input: &'a str) -> Result<Url<'a>, DataUrlParseError> {
+        let colon_offset: usize = input.find(':').ok_or(DataUrlParseError::UrlParseError)?;
+        let scheme: &str = &input[..colon_offset];
+        let scheme_is_valid: bool = scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+            && scheme
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'));
+        if !scheme_is_valid || !scheme.eq_ignore_ascii_case("data") {
+            return Err(DataUrlParseError::UrlParseError);
+        }
+
+        let rest: &str = &input[colon_offset + 1..];
+        let (rest, fragment): (&str, Option<&str>) = match rest.find('#') {
+            Some(offset) => (&rest[..offset], Some(&rest[offset + 1..])),
+            None => (rest, None),
+        };
+        let (path, query): (&str, Option<&str>) = match rest.find('?') {
+            Some(offset) => (&rest[..offset], Some(&rest[offset + 1..])),
+            None => (rest, None),
+        };
+
+        Ok(Url {
+            path: utf8_percent_encode(path, CONTROLS).into(),
+            query: query.map(Cow::Borrowed),
+            fragment: fragment.map(encode_fragment),
+        })
+    }
+
+    fn into_owned(self) -> Url<'static> {
+        Url {
+            path: Cow::Owned(self.path.into_owned()),
+            query: self.query.map(|q| Cow::Owned(q.into_owned())),
+            fragment: self.fragment,
+        }
+    }
+
+    pub(crate) fn path(&self) -> &str {
+        &self.path
+    }
+
+    pub(crate) fn query(&self) -> Option<&str> {
+        self.query.as_deref()
+    }
+
+    pub(crate) fn fragment(&self) -> Option<&str> {
+        self.fragment.as_deref()
+    }
+}
+
+// Shared with the streaming parser, which gets to the fragment on its own
+pub(crate) fn encode_fragment(fragment: &str) -> String {
+    utf8_percent_encode(fragment, FRAGMENT).to_string()
+}
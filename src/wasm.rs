@@ -0,0 +1,186 @@
+use js_sys::{Error, Object, Reflect, Uint8Array};
+use wasm_bindgen::prelude::*;
+
+use crate::{DataUrl, DataUrlBuildError, DataUrlParseError};
+
+fn parse_error_to_js(err: DataUrlParseError) -> JsValue {
+    let code: &str = match err {
+        DataUrlParseError::UrlParseError => "UrlParseError",
+        DataUrlParseError::MalformedDataUrlError => "MalformedDataUrlError",
+        DataUrlParseError::Base64DecodeError => "Base64DecodeError",
+        DataUrlParseError::LimitExceeded => "LimitExceeded",
+        DataUrlParseError::InvalidMediaType => "InvalidMediaType",
+        DataUrlParseError::InvalidParameter => "InvalidParameter",
+        DataUrlParseError::InvalidCharacter => "InvalidCharacter",
+        DataUrlParseError::DecompressionError => "DecompressionError",
+    };
+
+    js_error("DataUrlParseError", code)
+}
+
+fn build_error_to_js(err: DataUrlBuildError) -> JsValue {
+    let code: &str = match err {
+        DataUrlBuildError::InvalidMediaType => "InvalidMediaType",
+        DataUrlBuildError::InvalidCharset => "InvalidCharset",
+    };
+
+    js_error("DataUrlBuildError", code)
+}
+
+// Proper Error instances, so that JS code gets a stack trace and can switch on error.code
+fn js_error(name: &str, code: &str) -> JsValue {
+    let error: Error = Error::new(code);
+    error.set_name(name);
+    let _ = Reflect::set(&error, &"code".into(), &code.into());
+
+    error.into()
+}
+
+fn set(object: &Object, key: &str, value: JsValue) {
+    let _ = Reflect::set(object, &key.into(), &value);
+}
+
+fn get(object: &JsValue, key: &str) -> Option<JsValue> {
+    Reflect::get(object, &key.into())
+        .ok()
+        .filter(|value| !value.is_undefined() && !value.is_null())
+}
+
+#[wasm_bindgen(js_name = DataUrl)]
+pub struct JsDataUrl {
+    inner: DataUrl,
+}
+
+#[wasm_bindgen(js_class = DataUrl)]
+impl JsDataUrl {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> JsDataUrl {
+        JsDataUrl {
+            inner: DataUrl::new(),
+        }
+    }
+
+    pub fn parse(input: &str) -> Result<JsDataUrl, JsValue> {
+        DataUrl::parse(input)
+            .map(|inner| JsDataUrl { inner })
+            .map_err(parse_error_to_js)
+    }
+
+    #[wasm_bindgen(getter, js_name = mediaType)]
+    pub fn media_type(&self) -> String {
+        self.inner.media_type().to_string()
+    }
+
+    #[wasm_bindgen(setter, js_name = mediaType)]
+    pub fn set_media_type(&mut self, media_type: Option<String>) {
+        self.inner.set_media_type(media_type);
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn charset(&self) -> String {
+        self.inner.charset().to_string()
+    }
+
+    #[wasm_bindgen(setter)]
+    pub fn set_charset(&mut self, charset: Option<String>) {
+        self.inner.set_charset(charset);
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn base64(&self) -> bool {
+        self.inner.is_base64_encoded()
+    }
+
+    #[wasm_bindgen(setter)]
+    pub fn set_base64(&mut self, base64: bool) {
+        self.inner.set_is_base64_encoded(base64);
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn data(&self) -> Uint8Array {
+        Uint8Array::from(self.inner.data())
+    }
+
+    #[wasm_bindgen(setter)]
+    pub fn set_data(&mut self, data: &[u8]) {
+        self.inner.set_data(data);
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn fragment(&self) -> Option<String> {
+        self.inner.fragment()
+    }
+
+    #[wasm_bindgen(setter)]
+    pub fn set_fragment(&mut self, fragment: Option<String>) {
+        self.inner.set_fragment(fragment);
+    }
+
+    #[wasm_bindgen(getter, js_name = isBinary)]
+    pub fn is_binary(&self) -> bool {
+        self.inner.is_binary()
+    }
+
+    pub fn text(&self) -> String {
+        self.inner.text()
+    }
+
+    pub fn inspect(&self) -> Object {
+        let object: Object = Object::new();
+        set(&object, "mediaType", self.inner.media_type().into());
+        set(&object, "charset", self.inner.charset().into());
+        set(&object, "base64", self.inner.is_base64_encoded().into());
+        set(&object, "binary", self.inner.is_binary().into());
+        set(&object, "size", (self.inner.data().len() as f64).into());
+        set(&object, "fragment", self.inner.fragment().into());
+        object
+    }
+
+    #[allow(clippy::inherent_to_string)]
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_string(&self) -> String {
+        self.inner.to_string()
+    }
+}
+
+impl Default for JsDataUrl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[wasm_bindgen]
+pub fn parse(input: &str) -> Result<JsDataUrl, JsValue> {
+    JsDataUrl::parse(input)
+}
+
+// Options are a plain object: { mediaType, charset, base64, fragment }, all of them optional
+#[wasm_bindgen]
+pub fn encode(data: &[u8], options: JsValue) -> Result<String, JsValue> {
+    let mut builder = DataUrl::builder().data(data.to_vec());
+
+    if let Some(media_type) = get(&options, "mediaType").and_then(|v| v.as_string()) {
+        builder = builder.media_type(&media_type).map_err(build_error_to_js)?;
+    }
+    if let Some(charset) = get(&options, "charset").and_then(|v| v.as_string()) {
+        builder = builder.charset(&charset).map_err(build_error_to_js)?;
+    }
+    if let Some(base64) = get(&options, "base64").and_then(|v| v.as_bool()) {
+        builder = builder.base64(base64);
+    }
+    if let Some(fragment) = get(&options, "fragment").and_then(|v| v.as_string()) {
+        builder = builder.fragment(&fragment);
+    }
+
+    Ok(builder.build().to_string())
+}
+
+#[wasm_bindgen]
+pub fn inspect(input: &str) -> Result<Object, JsValue> {
+    JsDataUrl::parse(input).map(|data_url| data_url.inspect())
+}
+
+#[wasm_bindgen(js_name = toString)]
+pub fn to_string(data_url: &JsDataUrl) -> String {
+    data_url.to_string()
+}
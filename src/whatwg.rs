@@ -0,0 +1,187 @@
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use base64::{
+    alphabet,
+    engine::{DecodePaddingMode, GeneralPurpose, GeneralPurposeConfig},
+    Engine as _,
+};
+use encoding_rs::Encoding;
+
+use crate::{ContentEncoding, MetaData, Parameters};
+
+// Padding is optional and stray bits at the end are fine
+const FORGIVING_BASE64: GeneralPurpose = GeneralPurpose::new(
+    &alphabet::STANDARD,
+    GeneralPurposeConfig::new()
+        .with_decode_padding_mode(DecodePaddingMode::RequireNone)
+        .with_decode_allow_trailing_bits(true),
+);
+
+// https://fetch.spec.whatwg.org/#http-token-code-point
+fn is_token(s: &str) -> bool {
+    !s.is_empty()
+        && s.bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b"!#$%&'*+-.^_`|~".contains(&b))
+}
+
+// https://mimesniff.spec.whatwg.org/#http-quoted-string-token-code-point
+fn is_quoted_string_token(s: &str) -> bool {
+    s.chars()
+        .all(|c| c == '\t' || (' '..='~').contains(&c) || ('\u{80}'..='\u{ff}').contains(&c))
+}
+
+fn is_http_whitespace(c: char) -> bool {
+    matches!(c, '\t' | '\n' | '\r' | ' ')
+}
+
+// https://fetch.spec.whatwg.org/#collect-an-http-quoted-string (with the extract-value flag set)
+fn collect_quoted_string(input: &str) -> (String, &str) {
+    let mut value: String = String::new();
+    let mut chars = input[1..].char_indices();
+
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' => return (value, &input[i + 2..]),
+            '\\' => match chars.next() {
+                Some((_, c)) => value.push(c),
+                None => value.push('\\'),
+            },
+            c => value.push(c),
+        }
+    }
+
+    (value, "")
+}
+
+// https://mimesniff.spec.whatwg.org/#parse-a-mime-type
+fn parse_mime_type(input: &str) -> Option<(String, Parameters)> {
+    let input: &str = input.trim_matches(is_http_whitespace);
+    let (media_type, mut rest): (&str, &str) = input.split_once('/')?;
+    let (subtype, params): (&str, &str) = rest.split_once(';').unwrap_or((rest, ""));
+    let subtype: &str = subtype.trim_end_matches(is_http_whitespace);
+    if !is_token(media_type) || !is_token(subtype) {
+        return None;
+    }
+
+    let mut parameters: Parameters = vec![];
+    rest = params;
+    while !rest.is_empty() {
+        rest = rest.trim_start_matches(is_http_whitespace);
+        let name_end: usize = rest.find([';', '=']).unwrap_or(rest.len());
+        let name: String = rest[..name_end].to_lowercase();
+        rest = &rest[name_end..];
+
+        if !rest.starts_with('=') {
+            rest = rest.strip_prefix(';').unwrap_or(rest);
+            continue;
+        }
+        rest = &rest[1..];
+
+        let value: String = if rest.starts_with('"') {
+            let (value, remainder) = collect_quoted_string(rest);
+            rest = remainder;
+            value
+        } else {
+            let value_end: usize = rest.find(';').unwrap_or(rest.len());
+            let value: &str = rest[..value_end].trim_end_matches(is_http_whitespace);
+            rest = &rest[value_end..];
+            if value.is_empty() {
+                continue;
+            }
+            value.to_string()
+        };
+        rest = match rest.find(';') {
+            Some(offset) => &rest[offset + 1..],
+            None => "",
+        };
+
+        if is_token(&name)
+            && is_quoted_string_token(&value)
+            && !parameters.iter().any(|(n, _)| *n == name)
+        {
+            parameters.push((name, value));
+        }
+    }
+
+    Some((
+        format!("{}/{}", media_type, subtype).to_lowercase(),
+        parameters,
+    ))
+}
+
+// https://fetch.spec.whatwg.org/#data-url-processor
+pub(crate) fn parse_meta_data(meta_data: &str) -> MetaData {
+    let mut mime_type: &str = meta_data.trim_matches(|c: char| c.is_ascii_whitespace());
+    let mut is_base64_encoded: bool = false;
+
+    // Only a trailing ";base64" counts
+    if let Some(offset) = mime_type.rfind(';') {
+        if mime_type[offset + 1..]
+            .trim_start_matches(' ')
+            .eq_ignore_ascii_case("base64")
+        {
+            mime_type = &mime_type[..offset];
+            is_base64_encoded = true;
+        }
+    }
+
+    let has_media_type: bool = !mime_type.starts_with(';');
+    let parsed: Option<(String, Parameters)> = if has_media_type {
+        parse_mime_type(mime_type)
+    } else {
+        parse_mime_type(&format!("text/plain{}", mime_type))
+    };
+
+    let mut charset: Option<String> = None;
+    let mut content_encoding: Option<ContentEncoding> = None;
+    let mut parameters: Parameters = vec![];
+    // Anything unparsable becomes text/plain;charset=US-ASCII
+    let media_type: Option<String> = match parsed {
+        Some((media_type, all_parameters)) => {
+            for (name, value) in all_parameters {
+                match name.as_str() {
+                    "charset" => {
+                        charset = Encoding::for_label_no_replacement(value.as_bytes())
+                            .map(|e| e.name().to_string())
+                    }
+                    "content-encoding" => match ContentEncoding::from_name(&value) {
+                        Some(ce) => content_encoding = Some(ce),
+                        None => parameters.push((name, value)),
+                    },
+                    _ => parameters.push((name, value)),
+                }
+            }
+            Some(media_type).filter(|_| has_media_type)
+        }
+        None => None,
+    };
+
+    (
+        media_type,
+        charset,
+        content_encoding,
+        parameters,
+        is_base64_encoded,
+    )
+}
+
+// https://infra.spec.whatwg.org/#forgiving-base64-decode
+pub(crate) fn forgiving_base64_decode(data: &[u8]) -> Option<Vec<u8>> {
+    let mut data: Vec<u8> = data
+        .iter()
+        .copied()
+        .filter(|b| !matches!(b, b'\t' | b'\n' | b'\x0c' | b'\r' | b' '))
+        .collect();
+
+    if data.len().is_multiple_of(4) {
+        for _ in 0..2 {
+            if data.last() == Some(&b'=') {
+                data.pop();
+            }
+        }
+    }
+
+    FORGIVING_BASE64.decode(&data).ok()
+}
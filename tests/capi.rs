@@ -0,0 +1,78 @@
+// Run with: cargo test --features capi (requires a C compiler available as cc)
+#![cfg(feature = "capi")]
+
+//  ██████╗  █████╗ ███████╗███████╗██╗███╗   ██╗ ██████╗
+//  ██╔══██╗██╔══██╗██╔════╝██╔════╝██║████╗  ██║██╔════╝
+//  ██████╔╝███████║███████╗███████╗██║██╔██╗ ██║██║  ███╗
+//  ██╔═══╝ ██╔══██║╚════██║╚════██║██║██║╚██╗██║██║   ██║
+//  ██║     ██║  ██║███████║███████║██║██║ ╚████║╚██████╔╝
+//  ╚═╝     ╚═╝  ╚═╝╚══════╝╚══════╝╚═╝╚═╝  ╚═══╝ ╚═════╝
+
+#[cfg(test)]
+mod passing {
+    use std::env;
+    use std::fs;
+    use std::path::{Path, PathBuf};
+    use std::process::Command;
+
+    #[test]
+    fn must_have_up_to_date_header() {
+        let committed: PathBuf = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("include")
+            .join("dataurl.h");
+        let generated: String = fs::read_to_string(env!("DATAURL_GENERATED_HEADER")).unwrap();
+
+        // `make header` refreshes the committed copy
+        if env::var_os("DATAURL_UPDATE_HEADER").is_some() {
+            fs::write(&committed, &generated).unwrap();
+        }
+
+        // Committed header must match the one generated from src/capi.rs
+        assert!(
+            fs::read_to_string(&committed).unwrap() == generated,
+            "include/dataurl.h is out of date, run `make header`"
+        );
+    }
+
+    #[test]
+    fn must_work_from_c() {
+        let manifest_dir: &Path = Path::new(env!("CARGO_MANIFEST_DIR"));
+        // Test binaries live in target/<profile>/deps
+        let exe: PathBuf = env::current_exe().unwrap();
+        let target_dir: PathBuf = exe.ancestors().nth(3).unwrap().join("capi");
+        let profile_dir: PathBuf = target_dir.join("debug");
+        let output: PathBuf = profile_dir.join("capi-test");
+
+        // Static libraries aren't suffixed with a hash, hence a separate target directory
+        let status = Command::new(env!("CARGO"))
+            .args(["rustc", "--lib", "--crate-type", "staticlib"])
+            .args(["--no-default-features", "--features", "capi"])
+            .arg("--target-dir")
+            .arg(&target_dir)
+            .current_dir(manifest_dir)
+            .status()
+            .unwrap();
+
+        // Exit code must be 0
+        assert!(status.success());
+
+        let status = Command::new("cc")
+            .arg(manifest_dir.join("tests").join("capi").join("main.c"))
+            .arg("-I")
+            .arg(manifest_dir.join("include"))
+            .arg(profile_dir.join("libdataurl.a"))
+            .args(["-lpthread", "-ldl", "-lm"])
+            .arg("-o")
+            .arg(&output)
+            .status()
+            .unwrap();
+
+        // Exit code must be 0
+        assert!(status.success());
+
+        let status = Command::new(&output).status().unwrap();
+
+        // Exit code must be 0
+        assert!(status.success());
+    }
+}
@@ -0,0 +1,94 @@
+#include <stdio.h>
+#include <string.h>
+
+#include "dataurl.h"
+
+static int failures = 0;
+
+#define CHECK(condition)                                                  \
+    do {                                                                  \
+        if (!(condition)) {                                               \
+            fprintf(stderr, "%s:%d: check failed: %s\n", __FILE__,        \
+                    __LINE__, #condition);                                \
+            failures++;                                                   \
+        }                                                                 \
+    } while (0)
+
+static void must_parse_and_read_back(void) {
+    dataurl_t *data_url = NULL;
+    size_t len = 0;
+
+    CHECK(dataurl_parse("data:text/html;charset=utf-8;base64,PGI+PC9iPg==#f", &data_url) == DATAURL_OK);
+    CHECK(data_url != NULL);
+
+    char *media_type = dataurl_media_type(data_url);
+    CHECK(strcmp(media_type, "text/html") == 0);
+    dataurl_string_free(media_type);
+
+    char *charset = dataurl_charset(data_url);
+    CHECK(strcmp(charset, "UTF-8") == 0);
+    dataurl_string_free(charset);
+
+    CHECK(dataurl_is_base64(data_url));
+
+    const uint8_t *data = dataurl_data(data_url, &len);
+    CHECK(len == 7);
+    CHECK(memcmp(data, "<b></b>", len) == 0);
+
+    char *fragment = dataurl_fragment(data_url);
+    CHECK(strcmp(fragment, "f") == 0);
+    dataurl_string_free(fragment);
+
+    dataurl_free(data_url);
+}
+
+static void must_build_and_serialize(void) {
+    dataurl_t *data_url = dataurl_new();
+
+    CHECK(dataurl_set_media_type(data_url, "application/json") == DATAURL_OK);
+    CHECK(dataurl_set_charset(data_url, "utf8") == DATAURL_OK);
+    CHECK(dataurl_set_data(data_url, (const uint8_t *)"{}", 2) == DATAURL_OK);
+    CHECK(dataurl_set_base64(data_url, true) == DATAURL_OK);
+    CHECK(dataurl_set_fragment(data_url, "top") == DATAURL_OK);
+
+    char *s = dataurl_to_string(data_url);
+    CHECK(strcmp(s, "data:application/json;charset=UTF-8;base64,e30=#top") == 0);
+    dataurl_string_free(s);
+
+    CHECK(dataurl_set_fragment(data_url, NULL) == DATAURL_OK);
+    CHECK(dataurl_fragment(data_url) == NULL);
+
+    dataurl_free(data_url);
+}
+
+static void must_report_errors(void) {
+    dataurl_t *data_url = dataurl_new();
+
+    CHECK(dataurl_set_media_type(data_url, "bad media type") == DATAURL_ERR_INVALID_MEDIA_TYPE);
+    CHECK(dataurl_set_charset(data_url, "not-a-charset") == DATAURL_ERR_INVALID_CHARSET);
+    CHECK(dataurl_set_data(NULL, NULL, 0) == DATAURL_ERR_NULL_POINTER);
+
+    dataurl_free(data_url);
+
+    data_url = NULL;
+    CHECK(dataurl_parse("https://example.com", &data_url) == DATAURL_ERR_URL_PARSE);
+    CHECK(data_url == NULL);
+    CHECK(dataurl_parse("data:text/plain", &data_url) == DATAURL_ERR_MALFORMED);
+    CHECK(dataurl_parse("data:;base64,***", &data_url) == DATAURL_ERR_BASE64_DECODE);
+    CHECK(dataurl_parse(NULL, &data_url) == DATAURL_ERR_NULL_POINTER);
+
+    CHECK(strcmp(dataurl_error_message(DATAURL_ERR_MALFORMED), "malformed data URL") == 0);
+    CHECK(strcmp(dataurl_error_message(-1), "unknown error") == 0);
+    CHECK(strcmp(dataurl_error_message(12345), "unknown error") == 0);
+
+    dataurl_free(NULL);
+    dataurl_string_free(NULL);
+}
+
+int main(void) {
+    must_parse_and_read_back();
+    must_build_and_serialize();
+    must_report_errors();
+
+    return failures == 0 ? 0 : 1;
+}
@@ -22,23 +22,48 @@ Sunshine <sunshine@uberspace.net>
 CLI tool and Rust crate for parsing and generating data URLs
 
 USAGE:
-    {bin}{exe} [FLAGS] [OPTIONS] [INPUT]
+    {bin}{exe} [FLAGS] [OPTIONS] [INPUT] [SUBCOMMAND]
 
 FLAGS:
-    -b, --base64     Enforces base64 encoding
-    -d, --decode     Toggles decode mode on
-    -h, --help       Prints help information
-    -V, --version    Prints version information
+    -b, --base64       Enforces base64 encoding
+        --canonical    Parses input and outputs it as canonical data URL
+    -d, --decode       Toggles decode mode on
+    -h, --help         Prints help information
+        --preview      Outputs human-readable preview of decoded data (default for terminals)
+        --show         Displays decoded image in terminal, falls back to preview
+        --strict       Rejects input which doesn't conform to RFC 2397
+    -V, --version      Prints version information
 
 OPTIONS:
-    -c, --charset <ENCODING>           Sets custom encoding parameter
-    -f, --fragment <FRAGMENT>          Appends URL fragment
-    -i, --input-file <INPUT FILE>      Provides input file
-    -t, --media-type <MEDIA TYPE>      Sets custom media type
-    -o, --output-file <OUTPUT FILE>    Specifies output file
+        --alt <ALT TEXT>                      Sets alternative text of HTML image
+        --compress <COMPRESSION>              Compresses data before encoding it [possible values: gzip, deflate, br]
+    -c, --charset <ENCODING>                  Sets custom encoding parameter
+        --format <FORMAT>                     Sets output format [possible values: url, html]
+    -f, --fragment <FRAGMENT>                 Appends URL fragment
+        --hash <HASH ALGORITHM>               Outputs integrity hash instead of data URL [possible values: sha256,
+                                              sha384, sha512]
+        --to <IMAGE FORMAT>                   Re-encodes image into given format [possible values: jpeg, png, webp]
+    -i, --input-file <INPUT FILE>             Provides input file
+        --max-dimension <MAX DIMENSION>       Scales image down to fit given width and height
+        --max-size <MAX SIZE>                 Limits size of input and decoded data, in bytes
+    -t, --media-type <MEDIA TYPE>             Sets custom media type
+        --output-charset <OUTPUT ENCODING>    Sets encoding of decoded text (defaults to terminal locale)
+    -o, --output-file <OUTPUT FILE>           Specifies output file
+        --graphics-protocol <PROTOCOL>        Overrides terminal graphics protocol used by --show [possible values:
+                                              kitty, iterm2, sixel]
+        --quality <QUALITY>                   Sets quality of re-encoded JPEG image, from 1 to 100
+        --srcset <SRCSET FILE>...             Adds higher density version of HTML image, can be used more than once
+        --sri <SRI ALGORITHM>                 Outputs integrity hash along with data URL [possible values: sha256,
+                                              sha384, sha512]
+        --wrap <WIDTH>                        Wraps base64-encoded output into lines of given width (76 for MIME)
 
 ARGS:
     <INPUT>    Input string
+
+SUBCOMMANDS:
+    font    Outputs @font-face rule with font embedded as data URL
+    help    Prints this message or the help of the given subcommand(s)
+    mime    Converts HTML with data URLs into multipart/related MIME message (for email)
 ",
             bin = env!("CARGO_PKG_NAME"),
             ver = env!("CARGO_PKG_VERSION"),
@@ -98,7 +123,7 @@ mod failing {
                 "error: Found argument '-X' which wasn't expected, or isn't valid in this context
 
 USAGE:
-    {bin}{exe} [FLAGS] [OPTIONS] [INPUT]
+    {bin}{exe} [FLAGS] [OPTIONS] [INPUT] [SUBCOMMAND]
 
 For more information try --help\n",
                 bin = env!("CARGO_PKG_NAME"),
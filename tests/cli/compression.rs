@@ -0,0 +1,74 @@
+//  ██████╗  █████╗ ███████╗███████╗██╗███╗   ██╗ ██████╗
+//  ██╔══██╗██╔══██╗██╔════╝██╔════╝██║████╗  ██║██╔════╝
+//  ██████╔╝███████║███████╗███████╗██║██╔██╗ ██║██║  ███╗
+//  ██╔═══╝ ██╔══██║╚════██║╚════██║██║██║╚██╗██║██║   ██║
+//  ██║     ██║  ██║███████║███████║██║██║ ╚████║╚██████╔╝
+//  ╚═╝     ╚═╝  ╚═╝╚══════╝╚══════╝╚═╝╚═╝  ╚═══╝ ╚═════╝
+
+#[cfg(test)]
+mod passing {
+    use assert_cmd::prelude::*;
+    use std::process::Command;
+
+    #[test]
+    fn must_compress_data_when_compress_option_given() {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        let assert = cmd
+            .arg("-t")
+            .arg("application/json")
+            .arg("--compress")
+            .arg("deflate")
+            .arg("a")
+            .assert();
+
+        assert
+            // Exit code must be 0
+            .success()
+            // STDERR must be empty
+            .stderr("")
+            // STDOUT must contain compressed data URL
+            .stdout("data:application/json;content-encoding=deflate;base64,eNpLBAAAYgBi\n");
+    }
+
+    #[test]
+    fn must_transparently_decompress_data_when_decoding() {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        let assert = cmd
+            .arg("-d")
+            .arg("data:application/json;content-encoding=deflate;base64,eNpLBAAAYgBi")
+            .assert();
+
+        assert
+            // Exit code must be 0
+            .success()
+            // STDERR must be empty
+            .stderr("")
+            // STDOUT must contain decompressed data
+            .stdout("a");
+    }
+}
+
+//  ███████╗ █████╗ ██╗██╗     ██╗███╗   ██╗ ██████╗
+//  ██╔════╝██╔══██╗██║██║     ██║████╗  ██║██╔════╝
+//  █████╗  ███████║██║██║     ██║██╔██╗ ██║██║  ███╗
+//  ██╔══╝  ██╔══██║██║██║     ██║██║╚██╗██║██║   ██║
+//  ██║     ██║  ██║██║███████╗██║██║ ╚████║╚██████╔╝
+//  ╚═╝     ╚═╝  ╚═╝╚═╝╚══════╝╚═╝╚═╝  ╚═══╝ ╚═════╝
+
+#[cfg(test)]
+mod failing {
+    use assert_cmd::prelude::*;
+    use std::process::Command;
+
+    #[test]
+    fn must_fail_when_payload_cannot_be_decompressed() {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        let assert = cmd.arg("-d").arg("data:;content-encoding=gzip,a").assert();
+
+        assert
+            // Exit code must be 1
+            .failure()
+            // STDOUT must be empty
+            .stdout("");
+    }
+}
@@ -40,11 +40,86 @@ mod passing {
         );
 
         // STDERR must be empty
-        assert_eq!(&out.stderr, &[]);
+        assert_eq!(&out.stderr, &[] as &[u8]);
 
         // Exit code must be 0
         out.assert().code(0);
     }
+
+    #[test]
+    fn must_transcode_text_into_output_charset() {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        let out = cmd
+            .arg("-d")
+            .arg("--output-charset")
+            .arg("ISO-8859-2")
+            .arg("data:;charset=utf-8,%C5%81%C3%B3d%C5%BA")
+            .output()
+            .unwrap();
+
+        // STDOUT must contain ISO-8859-2 characters
+        assert_eq!(&out.stdout, &[163, 243, 100, 188]);
+
+        // STDERR must be empty
+        assert_eq!(&out.stderr, &[] as &[u8]);
+
+        // Exit code must be 0
+        out.assert().code(0);
+    }
+
+    #[test]
+    fn must_escape_characters_output_charset_cannot_represent() {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        let assert = cmd
+            .arg("-d")
+            .arg("--output-charset")
+            .arg("US-ASCII")
+            .arg("data:;charset=utf-8,%C3%9Cber%E2%82%AC")
+            .assert();
+
+        assert
+            // Exit code must be 0
+            .success()
+            // STDERR must be empty
+            .stderr("")
+            // STDOUT must contain escaped characters
+            .stdout("\\u{dc}ber\\u{20ac}");
+    }
+
+    #[test]
+    fn must_not_transcode_binary_data_into_output_charset() {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        let out = cmd
+            .arg("-d")
+            .arg("--output-charset")
+            .arg("US-ASCII")
+            .arg("data:application/octet-stream;base64,G1s/MQ==")
+            .output()
+            .unwrap();
+
+        // STDOUT must contain raw data
+        assert_eq!(&out.stdout, &[27, 91, 63, 49]);
+
+        // Exit code must be 0
+        out.assert().code(0);
+    }
+
+    #[test]
+    fn must_ignore_whitespace_in_base64_payload() {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        let assert = cmd
+            .arg("-d")
+            .arg("data:;base64,SGVs bG8g\r\n d29y%20bGQ=")
+            .assert();
+
+        assert
+            // Exit code must be 0
+            .success()
+            // STDERR must be empty
+            .stderr("")
+            // STDOUT must contain decoded data
+            .stdout("Hello world");
+    }
 }
 
 //  ███████╗ █████╗ ██╗██╗     ██╗███╗   ██╗ ██████╗
@@ -73,4 +148,23 @@ mod failing {
             // STDOUT must be empty
             .stdout("");
     }
+
+    #[test]
+    fn must_not_allow_incorrect_output_charset_to_be_set() {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        let assert = cmd
+            .arg("-d")
+            .arg("--output-charset")
+            .arg("BAD-CHARSET")
+            .arg("data:,a")
+            .assert();
+
+        assert
+            // Exit code must be 1
+            .failure()
+            // STDERR must contain error message
+            .stderr("error: Invalid encoding 'BAD-CHARSET'\n")
+            // STDOUT must be empty
+            .stdout("");
+    }
 }
@@ -8,6 +8,7 @@
 #[cfg(test)]
 mod passing {
     use assert_cmd::prelude::*;
+    use base64::{engine::general_purpose::STANDARD, Engine as _};
     use std::process::Command;
 
     #[test]
@@ -136,6 +137,178 @@ mod passing {
             // STDOUT must contain properly encoded data URL
             .stdout("data:;charset=GBK,%26%23220%3B\n");
     }
+
+    #[test]
+    #[cfg(unix)]
+    fn must_label_non_ascii_arg_input_with_locale_charset() {
+        use std::ffi::OsStr;
+        use std::os::unix::ffi::OsStrExt;
+
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        let assert = cmd
+            .env("LC_ALL", "pl_PL.ISO-8859-2")
+            .arg(OsStr::from_bytes(&[163, 243, 100, 188]))
+            .assert();
+
+        assert
+            // Exit code must be 0
+            .success()
+            // STDERR must be empty
+            .stderr("")
+            // STDOUT must contain data URL labeled with locale charset
+            .stdout("data:;charset=ISO-8859-2,%A3%F3d%BC\n");
+    }
+
+    #[test]
+    fn must_label_non_ascii_arg_input_as_utf8_in_ascii_locale() {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        let assert = cmd.env("LC_ALL", "C").arg("Ü").assert();
+
+        assert
+            // Exit code must be 0
+            .success()
+            // STDERR must be empty
+            .stderr("")
+            // STDOUT must contain data URL labeled as UTF-8
+            .stdout("data:;charset=UTF-8,%C3%9C\n");
+    }
+
+    #[test]
+    fn must_output_responsive_img_tag() {
+        let dir: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/_data_/");
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        let assert = cmd
+            .arg("-b")
+            .arg("-t")
+            .arg("image/png")
+            .arg("--format")
+            .arg("html")
+            .arg("--alt")
+            .arg("Dot")
+            .arg("-i")
+            .arg(format!("{}dot.png", dir))
+            .arg("--srcset")
+            .arg(format!("{}dot@2x.png", dir))
+            .assert();
+
+        assert
+            // Exit code must be 0
+            .success()
+            // STDERR must be empty
+            .stderr("")
+            // STDOUT must contain img tag with srcset, dimensions and alternative text
+            .stdout(
+                "<img src=\"data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAAAIAAAABCAIAAAB7QOjdAAAAC0lEQVR4nGNgAAMAAAcAAbKGrPQAAAAASUVORK5CYII=\" \
+                srcset=\"data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAAAQAAAACCAIAAADwyuo0AAAAC0lEQVR4nGNgwAUAABoAAbw84EEAAAAASUVORK5CYII= 2x\" \
+                width=\"2\" height=\"1\" alt=\"Dot\">\n",
+            );
+    }
+
+    #[test]
+    fn must_sniff_media_type_for_html_format() {
+        let dir: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/_data_/");
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        let assert = cmd
+            .arg("--format")
+            .arg("html")
+            .arg("-i")
+            .arg(format!("{}dot.png", dir))
+            .arg("--srcset")
+            .arg(format!("{}dot@2x.png", dir))
+            .assert();
+
+        assert
+            // Exit code must be 0
+            .success()
+            // STDERR must be empty
+            .stderr("")
+            // STDOUT must contain img tag with base64-encoded PNG images
+            .stdout(
+                "<img src=\"data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAAAIAAAABCAIAAAB7QOjdAAAAC0lEQVR4nGNgAAMAAAcAAbKGrPQAAAAASUVORK5CYII=\" \
+                srcset=\"data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAAAQAAAACCAIAAADwyuo0AAAAC0lEQVR4nGNgwAUAABoAAbw84EEAAAAASUVORK5CYII= 2x\" \
+                width=\"2\" height=\"1\">\n",
+            );
+    }
+
+    #[test]
+    fn must_base64_encode_binary_srcset_candidates() {
+        let dir: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/_data_/");
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        let out = cmd
+            .arg("-t")
+            .arg("image/png")
+            .arg("--format")
+            .arg("html")
+            .arg("-i")
+            .arg(format!("{}dot.png", dir))
+            .arg("--srcset")
+            .arg(format!("{}dot@2x.png", dir))
+            .output()
+            .unwrap();
+
+        // Exit code must be 0
+        assert!(out.status.success());
+        // STDOUT must contain base64-encoded srcset candidate
+        assert!(String::from_utf8(out.stdout).unwrap().contains(
+            "srcset=\"data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAAAQAAAACCAIAAADwyuo0AAAAC0lEQVR4nGNgwAUAABoAAbw84EEAAAAASUVORK5CYII= 2x\""
+        ));
+    }
+
+    #[test]
+    fn must_wrap_base64_output() {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        let assert = cmd
+            .arg("--wrap")
+            .arg("16")
+            .arg("Hello, world! Hello, world!")
+            .assert();
+
+        assert
+            // Exit code must be 0
+            .success()
+            // STDERR must be empty
+            .stderr("")
+            // STDOUT must contain data URL split into lines of 16 characters
+            .stdout("data:;base64,SGV\r\nsbG8sIHdvcmxkISB\r\nIZWxsbywgd29ybGQ\r\nh\n");
+    }
+
+    #[test]
+    fn must_output_img_tag_browsers_can_decode() {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        let out = cmd
+            .arg("-b")
+            .arg("-t")
+            .arg("image/png")
+            .arg("--format")
+            .arg("html")
+            .arg("-i")
+            .arg(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/tests/_data_/lizard.png"
+            ))
+            .output()
+            .unwrap();
+        let stdout: String = String::from_utf8(out.stdout).unwrap();
+        let payload: &str = stdout
+            .split("base64,")
+            .nth(1)
+            .unwrap()
+            .split('"')
+            .next()
+            .unwrap();
+
+        // Exit code must be 0
+        assert!(out.status.success());
+        // STDOUT must contain image encoded using standard base64 alphabet
+        assert_eq!(
+            STANDARD.decode(payload).unwrap(),
+            std::fs::read(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/tests/_data_/lizard.png"
+            ))
+            .unwrap()
+        );
+    }
 }
 
 //  ███████╗ █████╗ ██╗██╗     ██╗███╗   ██╗ ██████╗
@@ -177,4 +350,18 @@ mod failing {
             // STDOUT must be empty
             .stdout("");
     }
+
+    #[test]
+    fn must_not_allow_zero_wrap_width() {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        let assert = cmd.arg("--wrap").arg("0").arg("Hello").assert();
+
+        assert
+            // Exit code must be 1
+            .failure()
+            // STDERR must contain error message
+            .stderr("error: Invalid width '0'\n")
+            // STDOUT must be empty
+            .stdout("");
+    }
 }
@@ -0,0 +1,133 @@
+//  ██████╗  █████╗ ███████╗███████╗██╗███╗   ██╗ ██████╗
+//  ██╔══██╗██╔══██╗██╔════╝██╔════╝██║████╗  ██║██╔════╝
+//  ██████╔╝███████║███████╗███████╗██║██╔██╗ ██║██║  ███╗
+//  ██╔═══╝ ██╔══██║╚════██║╚════██║██║██║╚██╗██║██║   ██║
+//  ██║     ██║  ██║███████║███████║██║██║ ╚████║╚██████╔╝
+//  ╚═╝     ╚═╝  ╚═╝╚══════╝╚══════╝╚═╝╚═╝  ╚═══╝ ╚═════╝
+
+#[cfg(test)]
+mod passing {
+    use assert_cmd::prelude::*;
+    use std::env;
+    use std::fs;
+    use std::process::Command;
+
+    #[test]
+    fn must_output_font_face_rule() {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        let assert = cmd
+            .arg("font")
+            .arg(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/tests/_data_/font.woff2"
+            ))
+            .arg("--weight")
+            .arg("400")
+            .assert();
+
+        assert
+            // Exit code must be 0
+            .success()
+            // STDERR must be empty
+            .stderr("")
+            // STDOUT must contain @font-face rule, family named after the file
+            .stdout(
+                r#"@font-face {
+  font-family: "font";
+  src: url("data:font/woff2;base64,d09GMgABAAA=") format("woff2");
+  font-weight: 400;
+}
+"#,
+            );
+    }
+
+    #[test]
+    fn must_warn_about_large_fonts() {
+        let path = env::temp_dir().join("dataurl-must-warn-about-large-fonts.woff");
+        let mut font: Vec<u8> = b"wOFF".to_vec();
+        font.resize(200 * 1024, 0);
+        fs::write(&path, font).unwrap();
+
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        let out = cmd
+            .arg("font")
+            .arg("--family")
+            .arg("Big")
+            .arg(&path)
+            .output()
+            .unwrap();
+        fs::remove_file(&path).unwrap();
+
+        // STDERR must contain warning
+        assert_eq!(
+            String::from_utf8_lossy(&out.stderr),
+            "warning: Font is 204800 bytes large, consider subsetting it\n"
+        );
+
+        // STDOUT must contain @font-face rule
+        assert!(String::from_utf8_lossy(&out.stdout).starts_with(
+            "@font-face {\n  font-family: \"Big\";\n  src: url(\"data:font/woff;base64,"
+        ));
+
+        // Exit code must be 0
+        out.assert().code(0);
+    }
+}
+
+//  ███████╗ █████╗ ██╗██╗     ██╗███╗   ██╗ ██████╗
+//  ██╔════╝██╔══██╗██║██║     ██║████╗  ██║██╔════╝
+//  █████╗  ███████║██║██║     ██║██╔██╗ ██║██║  ███╗
+//  ██╔══╝  ██╔══██║██║██║     ██║██║╚██╗██║██║   ██║
+//  ██║     ██║  ██║██║███████╗██║██║ ╚████║╚██████╔╝
+//  ╚═╝     ╚═╝  ╚═╝╚═╝╚══════╝╚═╝╚═╝  ╚═══╝ ╚═════╝
+
+#[cfg(test)]
+mod failing {
+    use assert_cmd::prelude::*;
+    use std::process::Command;
+
+    #[test]
+    fn must_refuse_files_which_are_not_fonts() {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        let assert = cmd
+            .arg("font")
+            .arg(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/tests/_data_/text-file.txt"
+            ))
+            .assert();
+
+        assert
+            // Exit code must be 1
+            .failure()
+            // STDERR must contain error message
+            .stderr(format!(
+                "error: File '{}/tests/_data_/text-file.txt' is not a font\n",
+                env!("CARGO_MANIFEST_DIR")
+            ))
+            // STDOUT must be empty
+            .stdout("");
+    }
+
+    #[test]
+    fn must_refuse_invalid_font_weight() {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        let assert = cmd
+            .arg("font")
+            .arg("--weight")
+            .arg("400;} body{color:red")
+            .arg(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/tests/_data_/font.woff2"
+            ))
+            .assert();
+
+        assert
+            // Exit code must be 1
+            .failure()
+            // STDERR must contain error message
+            .stderr("error: Invalid font-weight value\n")
+            // STDOUT must be empty
+            .stdout("");
+    }
+}
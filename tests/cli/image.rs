@@ -0,0 +1,128 @@
+//  ██████╗  █████╗ ███████╗███████╗██╗███╗   ██╗ ██████╗
+//  ██╔══██╗██╔══██╗██╔════╝██╔════╝██║████╗  ██║██╔════╝
+//  ██████╔╝███████║███████╗███████╗██║██╔██╗ ██║██║  ███╗
+//  ██╔═══╝ ██╔══██║╚════██║╚════██║██║██║╚██╗██║██║   ██║
+//  ██║     ██║  ██║███████║███████║██║██║ ╚████║╚██████╔╝
+//  ╚═╝     ╚═╝  ╚═╝╚══════╝╚══════╝╚═╝╚═╝  ╚═══╝ ╚═════╝
+
+#[cfg(test)]
+mod passing {
+    use assert_cmd::prelude::*;
+    use std::process::Command;
+
+    #[test]
+    fn must_scale_image_down_and_convert_it() {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        let out = cmd
+            .arg("--max-dimension")
+            .arg("2")
+            .arg("--to")
+            .arg("webp")
+            .arg("-i")
+            .arg(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/tests/_data_/lizard.png"
+            ))
+            .output()
+            .unwrap();
+
+        // STDOUT must contain WebP data URL
+        assert!(String::from_utf8_lossy(&out.stdout).starts_with("data:image/webp;base64,UklGR"));
+
+        // STDERR must report sizes before and after
+        assert!(String::from_utf8_lossy(&out.stderr)
+            .starts_with("info: Image size changed from 14196 to "));
+
+        // Exit code must be 0
+        out.assert().code(0);
+    }
+
+    #[test]
+    fn must_warn_about_quality_having_no_effect_on_png_input() {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        let out = cmd
+            .arg("--max-dimension")
+            .arg("2")
+            .arg("--quality")
+            .arg("50")
+            .arg("-i")
+            .arg(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/tests/_data_/lizard.png"
+            ))
+            .output()
+            .unwrap();
+
+        // STDERR must contain warning
+        assert!(String::from_utf8_lossy(&out.stderr)
+            .starts_with("warning: Quality only affects JPEG output, ignoring it\n"));
+
+        // Exit code must be 0
+        out.assert().code(0);
+    }
+}
+
+//  ███████╗ █████╗ ██╗██╗     ██╗███╗   ██╗ ██████╗
+//  ██╔════╝██╔══██╗██║██║     ██║████╗  ██║██╔════╝
+//  █████╗  ███████║██║██║     ██║██╔██╗ ██║██║  ███╗
+//  ██╔══╝  ██╔══██║██║██║     ██║██║╚██╗██║██║   ██║
+//  ██║     ██║  ██║██║███████╗██║██║ ╚████║╚██████╔╝
+//  ╚═╝     ╚═╝  ╚═╝╚═╝╚══════╝╚═╝╚═╝  ╚═══╝ ╚═════╝
+
+#[cfg(test)]
+mod failing {
+    use assert_cmd::prelude::*;
+    use std::process::Command;
+
+    #[test]
+    fn must_not_allow_invalid_quality() {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        let assert = cmd.arg("--quality").arg("101").arg("x").assert();
+
+        assert
+            // Exit code must be 1
+            .failure()
+            // STDERR must contain error message
+            .stderr("error: Invalid quality '101'\n")
+            // STDOUT must be empty
+            .stdout("");
+    }
+
+    #[test]
+    fn must_fail_to_reencode_non_image() {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        let assert = cmd.arg("--to").arg("png").arg("x").assert();
+
+        assert
+            // Exit code must be 1
+            .failure()
+            // STDERR must contain error message
+            .stderr("error: Unable to re-encode image (The image format could not be determined)\n")
+            // STDOUT must be empty
+            .stdout("");
+    }
+
+    #[test]
+    fn must_not_allow_quality_for_lossless_output() {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        let assert = cmd
+            .arg("--to")
+            .arg("webp")
+            .arg("--quality")
+            .arg("80")
+            .arg("-i")
+            .arg(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/tests/_data_/lizard.png"
+            ))
+            .assert();
+
+        assert
+            // Exit code must be 1
+            .failure()
+            // STDERR must contain error message
+            .stderr("error: Quality only applies to JPEG, PNG and WebP output is lossless\n")
+            // STDOUT must be empty
+            .stdout("");
+    }
+}
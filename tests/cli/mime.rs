@@ -0,0 +1,84 @@
+//  ██████╗  █████╗ ███████╗███████╗██╗███╗   ██╗ ██████╗
+//  ██╔══██╗██╔══██╗██╔════╝██╔════╝██║████╗  ██║██╔════╝
+//  ██████╔╝███████║███████╗███████╗██║██╔██╗ ██║██║  ███╗
+//  ██╔═══╝ ██╔══██║╚════██║╚════██║██║██║╚██╗██║██║   ██║
+//  ██║     ██║  ██║███████║███████║██║██║ ╚████║╚██████╔╝
+//  ╚═╝     ╚═╝  ╚═╝╚══════╝╚══════╝╚═╝╚═╝  ╚═══╝ ╚═════╝
+
+#[cfg(test)]
+mod passing {
+    use std::env;
+
+    #[test]
+    fn must_convert_html_into_multipart_message() {
+        // STDIN needs assert_cmd's own Command
+        let mut cmd = assert_cmd::Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        let out = cmd
+            .arg("mime")
+            .write_stdin(r#"<img src="data:image/png;base64,iVBORw0KGgo=">"#)
+            .output()
+            .unwrap();
+        let stdout: String = String::from_utf8(out.stdout).unwrap();
+
+        // Exit code must be 0
+        assert!(out.status.success());
+        // STDERR must be empty
+        assert!(out.stderr.is_empty());
+        // STDOUT must contain image as separate part
+        assert!(stdout.starts_with("MIME-Version: 1.0\r\n"));
+        assert!(stdout.contains("\r\nContent-Type: image/png\r\n"));
+        assert!(stdout.contains("\r\n\r\niVBORw0KGgo=\r\n"));
+    }
+
+    #[test]
+    fn must_convert_multipart_message_back_into_html() {
+        // STDIN needs assert_cmd's own Command
+        let mut cmd = assert_cmd::Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        let assert = cmd
+            .arg("mime")
+            .arg("-d")
+            .write_stdin(
+                "Content-Type: multipart/related; boundary=b\n\n--b\nContent-Type: text/html\n\n<img src=\"cid:dot\">\n--b\nContent-Type: text/plain\nContent-ID: <dot>\n\nHi\n--b--\n",
+            )
+            .assert();
+
+        assert
+            // Exit code must be 0
+            .success()
+            // STDERR must be empty
+            .stderr("")
+            // STDOUT must contain HTML with part embedded as data URL
+            .stdout("<img src=\"data:text/plain;base64,SGk=\">");
+    }
+}
+
+//  ███████╗ █████╗ ██╗██╗     ██╗███╗   ██╗ ██████╗
+//  ██╔════╝██╔══██╗██║██║     ██║████╗  ██║██╔════╝
+//  █████╗  ███████║██║██║     ██║██╔██╗ ██║██║  ███╗
+//  ██╔══╝  ██╔══██║██║██║     ██║██║╚██╗██║██║   ██║
+//  ██║     ██║  ██║██║███████╗██║██║ ╚████║╚██████╔╝
+//  ╚═╝     ╚═╝  ╚═╝╚═╝╚══════╝╚═╝╚═╝  ╚═══╝ ╚═════╝
+
+#[cfg(test)]
+mod failing {
+    use std::env;
+
+    #[test]
+    fn must_fail_when_message_has_no_html_part() {
+        // STDIN needs assert_cmd's own Command
+        let mut cmd = assert_cmd::Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        let assert = cmd
+            .arg("mime")
+            .arg("--decode")
+            .write_stdin("Content-Type: image/png\n\nHi")
+            .assert();
+
+        assert
+            // Exit code must be 1
+            .failure()
+            // STDERR must contain error message
+            .stderr("error: MIME message has no HTML part\n")
+            // STDOUT must be empty
+            .stdout("");
+    }
+}
@@ -1,3 +1,13 @@
 mod basic;
+mod canonical;
+mod compression;
 mod decode;
 mod encode;
+mod font;
+mod image;
+mod integrity;
+mod limits;
+mod mime;
+mod preview;
+mod show;
+mod strict;
@@ -0,0 +1,141 @@
+//  ██████╗  █████╗ ███████╗███████╗██╗███╗   ██╗ ██████╗
+//  ██╔══██╗██╔══██╗██╔════╝██╔════╝██║████╗  ██║██╔════╝
+//  ██████╔╝███████║███████╗███████╗██║██╔██╗ ██║██║  ███╗
+//  ██╔═══╝ ██╔══██║╚════██║╚════██║██║██║╚██╗██║██║   ██║
+//  ██║     ██║  ██║███████║███████║██║██║ ╚████║╚██████╔╝
+//  ╚═╝     ╚═╝  ╚═╝╚══════╝╚══════╝╚═╝╚═╝  ╚═══╝ ╚═════╝
+
+#[cfg(test)]
+mod passing {
+    use assert_cmd::prelude::*;
+    use std::process::Command;
+
+    #[test]
+    fn must_output_hexdump_of_binary_data() {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        let assert = cmd
+            .arg("-d")
+            .arg("--preview")
+            .arg("data:application/octet-stream;base64,AAECAwQFBgcICQoLDA0ODxtbMW0=")
+            .assert();
+
+        assert
+            // Exit code must be 0
+            .success()
+            // STDERR must be empty
+            .stderr("")
+            // STDOUT must contain hexdump
+            .stdout(
+                "00000000: 0001 0203 0405 0607 0809 0a0b 0c0d 0e0f  ................
+00000010: 1b5b 316d                                .[1m
+",
+            );
+    }
+
+    #[test]
+    fn must_pretty_print_json() {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        let assert = cmd
+            .arg("-d")
+            .arg("--preview")
+            .arg("data:application/json,{\"a\":[1,{\"b\":\"c,d\"}],\"e\":{}}")
+            .assert();
+
+        assert
+            // Exit code must be 0
+            .success()
+            // STDERR must be empty
+            .stderr("")
+            // STDOUT must contain indented JSON
+            .stdout(
+                r#"{
+  "a": [
+    1,
+    {
+      "b": "c,d"
+    }
+  ],
+  "e": {}
+}
+"#,
+            );
+    }
+
+    #[test]
+    fn must_pretty_print_xml() {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        let assert = cmd
+            .arg("-d")
+            .arg("--preview")
+            .arg("data:text/xml,<?xml version=\"1.0\"?><a><b x=\"<>\"/><c>text</c><d></d></a>")
+            .assert();
+
+        assert
+            // Exit code must be 0
+            .success()
+            // STDERR must be empty
+            .stderr("")
+            // STDOUT must contain indented XML
+            .stdout(
+                r#"<?xml version="1.0"?>
+<a>
+  <b x="<>"/>
+  <c>text</c>
+  <d></d>
+</a>
+"#,
+            );
+    }
+
+    #[test]
+    fn must_summarize_images() {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        let assert = cmd
+            .arg("-d")
+            .arg("--preview")
+            .arg("data:image/gif;base64,R0lGODlhAQABAIAAAAAAAP///yH5BAEAAAAALAAAAAABAAEAAAIBRAA7")
+            .assert();
+
+        assert
+            // Exit code must be 0
+            .success()
+            // STDERR must be empty
+            .stderr("")
+            // STDOUT must contain format, dimensions and size
+            .stdout("image/gif, 1x1, 42 bytes\n");
+    }
+
+    #[test]
+    fn must_escape_control_characters_in_text() {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        let assert = cmd
+            .arg("-d")
+            .arg("--preview")
+            .arg("data:,a%1B[2Jb%0Ac")
+            .assert();
+
+        assert
+            // Exit code must be 0
+            .success()
+            // STDERR must be empty
+            .stderr("")
+            // STDOUT must contain escaped control characters
+            .stdout("a\\u{1b}[2Jb\nc");
+    }
+
+    #[test]
+    fn must_output_raw_data_without_preview_flag_when_piped() {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        let out = cmd
+            .arg("-d")
+            .arg("data:application/json,{%20}")
+            .output()
+            .unwrap();
+
+        // STDOUT must contain data as is
+        assert_eq!(&out.stdout, b"{ }");
+
+        // Exit code must be 0
+        out.assert().code(0);
+    }
+}
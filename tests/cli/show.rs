@@ -0,0 +1,151 @@
+//  ██████╗  █████╗ ███████╗███████╗██╗███╗   ██╗ ██████╗
+//  ██╔══██╗██╔══██╗██╔════╝██╔════╝██║████╗  ██║██╔════╝
+//  ██████╔╝███████║███████╗███████╗██║██╔██╗ ██║██║  ███╗
+//  ██╔═══╝ ██╔══██║╚════██║╚════██║██║██║╚██╗██║██║   ██║
+//  ██║     ██║  ██║███████║███████║██║██║ ╚████║╚██████╔╝
+//  ╚═╝     ╚═╝  ╚═╝╚══════╝╚══════╝╚═╝╚═╝  ╚═══╝ ╚═════╝
+
+#[cfg(test)]
+mod passing {
+    use assert_cmd::prelude::*;
+    use std::process::Command;
+
+    #[test]
+    fn must_pass_png_through_to_kitty() {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        let assert = cmd
+            .arg("-d")
+            .arg("--graphics-protocol")
+            .arg("kitty")
+            .arg("data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAAAMAAAAHCAYAAADNufepAAAAFElEQVR4nGP4z8AAQkDMAGFRjQMAH4Ib5b2X/LIAAAAASUVORK5CYII=")
+            .assert();
+
+        assert
+            // Exit code must be 0
+            .success()
+            // STDERR must be empty
+            .stderr("")
+            // STDOUT must contain kitty graphics command
+            .stdout("\x1b_Ga=T,f=100,m=0;iVBORw0KGgoAAAANSUhEUgAAAAMAAAAHCAYAAADNufepAAAAFElEQVR4nGP4z8AAQkDMAGFRjQMAH4Ib5b2X/LIAAAAASUVORK5CYII=\x1b\\\n");
+    }
+
+    #[test]
+    fn must_send_other_formats_to_kitty_as_rgba() {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        let assert = cmd
+            .arg("-d")
+            .arg("--graphics-protocol")
+            .arg("kitty")
+            .arg("data:image/gif;base64,R0lGODlhAQABAIAAAAAAAP///yH5BAEAAAAALAAAAAABAAEAAAIBRAA7")
+            .assert();
+
+        assert
+            // Exit code must be 0
+            .success()
+            // STDERR must be empty
+            .stderr("")
+            // STDOUT must contain kitty graphics command with decoded pixels
+            .stdout("\x1b_Ga=T,f=32,s=1,v=1,m=0;AAAAAA==\x1b\\\n");
+    }
+
+    #[test]
+    fn must_send_image_file_to_iterm2() {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        let assert = cmd
+            .arg("-d")
+            .arg("--graphics-protocol")
+            .arg("iterm2")
+            .arg("data:image/gif;base64,R0lGODlhAQABAIAAAAAAAP///yH5BAEAAAAALAAAAAABAAEAAAIBRAA7")
+            .assert();
+
+        assert
+            // Exit code must be 0
+            .success()
+            // STDERR must be empty
+            .stderr("")
+            // STDOUT must contain iTerm2 inline image
+            .stdout("\x1b]1337;File=inline=1;size=42;preserveAspectRatio=1:R0lGODlhAQABAIAAAAAAAP///yH5BAEAAAAALAAAAAABAAEAAAIBRAA7\x07\n");
+    }
+
+    #[test]
+    fn must_encode_image_as_sixel() {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        let assert = cmd
+            .arg("-d")
+            .arg("--graphics-protocol")
+            .arg("sixel")
+            .arg("data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAAAMAAAAHCAYAAADNufepAAAAFElEQVR4nGP4z8AAQkDMAGFRjQMAH4Ib5b2X/LIAAAAASUVORK5CYII=")
+            .assert();
+
+        assert
+            // Exit code must be 0
+            .success()
+            // STDERR must be empty
+            .stderr("")
+            // STDOUT must contain sixel image, transparent pixels left out
+            .stdout(
+                "\x1bPq\"1;1;3;7#5;2;0;0;100#180;2;100;0;0#5?~?$#180~??-#5?@?$#180@??-\x1b\\\n",
+            );
+    }
+
+    #[test]
+    fn must_detect_graphics_protocol_from_environment() {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        let assert = cmd
+            .env_remove("KITTY_WINDOW_ID")
+            .env("TERM_PROGRAM", "iTerm.app")
+            .env("TERM", "xterm-256color")
+            .arg("-d")
+            .arg("--show")
+            .arg("data:image/gif;base64,R0lGODlhAQABAIAAAAAAAP///yH5BAEAAAAALAAAAAABAAEAAAIBRAA7")
+            .assert();
+
+        assert
+            // Exit code must be 0
+            .success()
+            // STDERR must be empty
+            .stderr("")
+            // STDOUT must contain iTerm2 inline image
+            .stdout("\x1b]1337;File=inline=1;size=42;preserveAspectRatio=1:R0lGODlhAQABAIAAAAAAAP///yH5BAEAAAAALAAAAAABAAEAAAIBRAA7\x07\n");
+    }
+
+    #[test]
+    fn must_fall_back_to_preview_if_terminal_has_no_graphics() {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        let assert = cmd
+            .env_remove("KITTY_WINDOW_ID")
+            .env_remove("TERM_PROGRAM")
+            .env("TERM", "dumb")
+            .arg("-d")
+            .arg("--show")
+            .arg("data:image/gif;base64,R0lGODlhAQABAIAAAAAAAP///yH5BAEAAAAALAAAAAABAAEAAAIBRAA7")
+            .assert();
+
+        assert
+            // Exit code must be 0
+            .success()
+            // STDERR must be empty
+            .stderr("")
+            // STDOUT must contain image summary
+            .stdout("image/gif, 1x1, 42 bytes\n");
+    }
+
+    #[test]
+    fn must_fall_back_to_preview_if_data_is_not_an_image() {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        let assert = cmd
+            .arg("-d")
+            .arg("--graphics-protocol")
+            .arg("kitty")
+            .arg("data:application/octet-stream,%00%01")
+            .assert();
+
+        assert
+            // Exit code must be 0
+            .success()
+            // STDERR must be empty
+            .stderr("")
+            // STDOUT must contain hexdump
+            .stdout("00000000: 0001                                     ..\n");
+    }
+}
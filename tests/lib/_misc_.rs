@@ -8,6 +8,8 @@
 #[cfg(test)]
 mod passing {
     use dataurl::{DataUrl, DataUrlParseError};
+    #[cfg(feature = "std")]
+    use std::error::Error;
 
     #[test]
     fn must_have_correct_code_for_the_readme_usage_snippet() -> Result<(), DataUrlParseError> {
@@ -28,4 +30,27 @@ mod passing {
 
         Ok(())
     }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn must_have_correct_code_for_the_readme_builder_snippet() -> Result<(), Box<dyn Error>> {
+        fn embed(path: &str) -> Result<DataUrl, Box<dyn Error>> {
+            let data_url: DataUrl = DataUrl::builder()
+                .media_type("image/png")?
+                .base64(true)
+                .data(std::fs::read(path)?)
+                .build();
+
+            Ok(data_url)
+        }
+        let path: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/_data_/dot.png");
+
+        assert_eq!(
+            embed(path)?.to_string(),
+            DataUrl::from_file(path)?.to_string()
+        );
+        assert!(embed("nonexistent.png").is_err());
+
+        Ok(())
+    }
 }
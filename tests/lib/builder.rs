@@ -0,0 +1,124 @@
+//  ██████╗  █████╗ ███████╗███████╗██╗███╗   ██╗ ██████╗
+//  ██╔══██╗██╔══██╗██╔════╝██╔════╝██║████╗  ██║██╔════╝
+//  ██████╔╝███████║███████╗███████╗██║██╔██╗ ██║██║  ███╗
+//  ██╔═══╝ ██╔══██║╚════██║╚════██║██║██║╚██╗██║██║   ██║
+//  ██║     ██║  ██║███████║███████║██║██║ ╚████║╚██████╔╝
+//  ╚═╝     ╚═╝  ╚═╝╚══════╝╚══════╝╚═╝╚═╝  ╚═══╝ ╚═════╝
+
+#[cfg(test)]
+mod passing {
+    use dataurl::{Bytes, DataUrl, DataUrlBuildError};
+
+    #[test]
+    fn must_build_empty_data_url_by_default() -> Result<(), DataUrlBuildError> {
+        let data_url = DataUrl::builder().build();
+
+        assert_eq!(data_url.to_string(), "data:,");
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_build_data_url_with_all_properties_set() -> Result<(), DataUrlBuildError> {
+        let data_url = DataUrl::builder()
+            .media_type("image/png")?
+            .charset("utf-8")?
+            .base64(true)
+            .fragment("x")
+            .data([0x89, 0x50, 0x4e, 0x47].as_ref())
+            .build();
+
+        assert_eq!(data_url.media_type(), "image/png");
+        assert_eq!(data_url.charset(), "UTF-8");
+        assert!(data_url.is_base64_encoded());
+        assert_eq!(data_url.data(), [0x89, 0x50, 0x4e, 0x47]);
+        assert_eq!(data_url.fragment(), Some("x".to_string()));
+        assert_eq!(
+            data_url.to_string(),
+            "data:image/png;charset=UTF-8;base64,iVBORw==#x"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_accept_owned_data() -> Result<(), DataUrlBuildError> {
+        let data_url = DataUrl::builder().data(b"Hello".to_vec()).build();
+
+        assert_eq!(data_url.text(), "Hello");
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_share_bytes_instead_of_copying_them() -> Result<(), DataUrlBuildError> {
+        let data: Bytes = Bytes::from(b"Hello".to_vec());
+        let data_url = DataUrl::builder().data(data.clone()).build();
+
+        assert_eq!(data_url.data().as_ptr(), data.as_ptr());
+
+        Ok(())
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn must_sniff_media_type_from_data() {
+        let data_url = DataUrl::builder()
+            .data(b"\x89PNG\r\n\x1a\n".to_vec())
+            .sniff_media_type()
+            .build();
+
+        assert_eq!(data_url.media_type(), "image/png");
+        assert!(data_url.is_base64_encoded());
+
+        let data_url = DataUrl::builder().data("Hello").sniff_media_type().build();
+
+        assert_eq!(data_url.media_type_no_default(), None);
+        assert!(!data_url.is_base64_encoded());
+    }
+}
+
+//  ███████╗ █████╗ ██╗██╗     ██╗███╗   ██╗ ██████╗
+//  ██╔════╝██╔══██╗██║██║     ██║████╗  ██║██╔════╝
+//  █████╗  ███████║██║██║     ██║██╔██╗ ██║██║  ███╗
+//  ██╔══╝  ██╔══██║██║██║     ██║██║╚██╗██║██║   ██║
+//  ██║     ██║  ██║██║███████╗██║██║ ╚████║╚██████╔╝
+//  ╚═╝     ╚═╝  ╚═╝╚═╝╚══════╝╚═╝╚═╝  ╚═══╝ ╚═════╝
+
+#[cfg(test)]
+mod failing {
+    use dataurl::{DataUrl, DataUrlBuildError};
+
+    #[test]
+    fn must_return_error_if_given_invalid_media_type() {
+        let result = DataUrl::builder().media_type("wrong/media/type");
+
+        assert!(matches!(result, Err(DataUrlBuildError::InvalidMediaType)));
+    }
+
+    #[test]
+    fn must_return_error_if_given_empty_media_type() {
+        let result = DataUrl::builder().media_type(" ");
+
+        assert!(matches!(result, Err(DataUrlBuildError::InvalidMediaType)));
+    }
+
+    #[test]
+    fn must_return_error_if_given_bad_charset() {
+        let result = DataUrl::builder().charset("BAD-CHARSET");
+
+        assert!(matches!(result, Err(DataUrlBuildError::InvalidCharset)));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn must_be_usable_as_std_error() {
+        let err: Box<dyn std::error::Error> = DataUrl::builder()
+            .charset("BAD-CHARSET")
+            .err()
+            .unwrap()
+            .into();
+
+        assert_eq!(err.to_string(), "invalid charset");
+    }
+}
@@ -0,0 +1,37 @@
+//  ██████╗  █████╗ ███████╗███████╗██╗███╗   ██╗ ██████╗
+//  ██╔══██╗██╔══██╗██╔════╝██╔════╝██║████╗  ██║██╔════╝
+//  ██████╔╝███████║███████╗███████╗██║██╔██╗ ██║██║  ███╗
+//  ██╔═══╝ ██╔══██║╚════██║╚════██║██║██║╚██╗██║██║   ██║
+//  ██║     ██║  ██║███████║███████║██║██║ ╚████║╚██████╔╝
+//  ╚═╝     ╚═╝  ╚═╝╚══════╝╚══════╝╚═╝╚═╝  ╚═══╝ ╚═════╝
+
+#[cfg(test)]
+mod passing {
+    use dataurl::{DataUrl, DataUrlParseError};
+
+    #[test]
+    fn must_normalize_properties_in_place() -> Result<(), DataUrlParseError> {
+        let mut data_url: DataUrl = DataUrl::parse("data:text/plain;charset=US-ASCII;base64,YQ==")?;
+
+        data_url.canonicalize();
+
+        assert_eq!(data_url.media_type_no_default(), None);
+        assert_eq!(data_url.charset_no_default(), None);
+        assert!(!data_url.is_base64_encoded());
+        assert_eq!(data_url.to_string(), "data:,a");
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_switch_binary_data_to_base64() -> Result<(), DataUrlParseError> {
+        let mut data_url: DataUrl = DataUrl::parse("data:Image/GIF,GIF89a")?;
+
+        data_url.canonicalize();
+
+        assert_eq!(data_url.media_type(), "image/gif");
+        assert!(data_url.is_base64_encoded());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,172 @@
+//  ██████╗  █████╗ ███████╗███████╗██╗███╗   ██╗ ██████╗
+//  ██╔══██╗██╔══██╗██╔════╝██╔════╝██║████╗  ██║██╔════╝
+//  ██████╔╝███████║███████╗███████╗██║██╔██╗ ██║██║  ███╗
+//  ██╔═══╝ ██╔══██║╚════██║╚════██║██║██║╚██╗██║██║   ██║
+//  ██║     ██║  ██║███████║███████║██║██║ ╚████║╚██████╔╝
+//  ╚═╝     ╚═╝  ╚═╝╚══════╝╚══════╝╚═╝╚═╝  ╚═══╝ ╚═════╝
+
+#[cfg(test)]
+mod passing {
+    use dataurl::{ContentEncoding, DataUrl, DataUrlParseError, ParseOptions};
+
+    #[test]
+    fn must_round_trip_through_every_content_encoding() -> std::io::Result<()> {
+        for content_encoding in [
+            ContentEncoding::Gzip,
+            ContentEncoding::Deflate,
+            ContentEncoding::Brotli,
+        ] {
+            let mut data_url = DataUrl::from_text("{\"a\":[1,2,3,1,2,3,1,2,3]}");
+            data_url.set_media_type(Some("application/json".to_string()));
+
+            data_url.compress(content_encoding)?;
+
+            assert_eq!(data_url.content_encoding(), Some(content_encoding));
+            assert!(data_url.is_base64_encoded());
+            assert_eq!(
+                data_url.decompressed_data()?,
+                b"{\"a\":[1,2,3,1,2,3,1,2,3]}".to_vec()
+            );
+
+            data_url.decompress()?;
+
+            assert_eq!(data_url.content_encoding(), None);
+            assert_eq!(data_url.text(), "{\"a\":[1,2,3,1,2,3,1,2,3]}");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_tag_compressed_payload_with_parameter() -> std::io::Result<()> {
+        let mut data_url = DataUrl::from_text("a");
+        data_url.set_media_type(Some("application/json".to_string()));
+
+        data_url.compress(ContentEncoding::Deflate)?;
+
+        assert_eq!(
+            data_url.to_string(),
+            "data:application/json;content-encoding=deflate;base64,eNpLBAAAYgBi"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_parse_content_encoding_parameter() -> Result<(), DataUrlParseError> {
+        let data_url: DataUrl = DataUrl::parse(
+            "data:application/json;Content-Encoding=GZIP;base64,H4sIAAAAAAAC_0sEAEO-t-gBAAAA",
+        )?;
+
+        assert_eq!(data_url.content_encoding(), Some(ContentEncoding::Gzip));
+        assert_eq!(data_url.decompressed_data().unwrap(), b"a".to_vec());
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_decompress_while_parsing_if_told_to() -> Result<(), DataUrlParseError> {
+        let options = ParseOptions {
+            max_decoded_len: Some(32),
+            decompress: true,
+            ..ParseOptions::default()
+        };
+        let input: &str =
+            "data:application/json;content-encoding=gzip;base64,H4sIAAAAAAAC_0sEAEO-t-gBAAAA";
+
+        let data_url: DataUrl = DataUrl::parse_with_options(input, &options)?;
+        assert_eq!(data_url.content_encoding(), None);
+        assert_eq!(data_url.data(), b"a");
+
+        let data_url: DataUrl = DataUrl::parse_lossless_with_options(input, &options)?;
+        assert_eq!(data_url.to_string(), "data:application/json;base64,YQ==");
+
+        Ok(())
+    }
+}
+
+//  ███████╗ █████╗ ██╗██╗     ██╗███╗   ██╗ ██████╗
+//  ██╔════╝██╔══██╗██║██║     ██║████╗  ██║██╔════╝
+//  █████╗  ███████║██║██║     ██║██╔██╗ ██║██║  ███╗
+//  ██╔══╝  ██╔══██║██║██║     ██║██║╚██╗██║██║   ██║
+//  ██║     ██║  ██║██║███████╗██║██║ ╚████║╚██████╔╝
+//  ╚═╝     ╚═╝  ╚═╝╚═╝╚══════╝╚═╝╚═╝  ╚═══╝ ╚═════╝
+
+#[cfg(test)]
+mod failing {
+    use dataurl::{ContentEncoding, DataUrl, DataUrlParseError, ParseOptions};
+
+    #[test]
+    fn must_keep_unknown_content_encoding_and_refuse_to_decompress() -> Result<(), DataUrlParseError>
+    {
+        let mut data_url: DataUrl = DataUrl::parse("data:;content-encoding=zstd,a")?;
+
+        assert_eq!(data_url.content_encoding(), None);
+        assert_eq!(data_url.parameter("content-encoding"), Some("zstd"));
+        assert_eq!(data_url.to_string(), "data:;content-encoding=zstd,a");
+        assert!(data_url.decompress().is_err());
+        assert!(data_url.compress(ContentEncoding::Gzip).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_error_out_if_payload_is_not_actually_compressed() {
+        let mut data_url = DataUrl::from_text("a");
+        data_url.set_content_encoding(Some(ContentEncoding::Gzip));
+
+        assert!(data_url.decompress().is_err());
+        assert_eq!(data_url.data(), b"a");
+    }
+
+    #[test]
+    fn must_refuse_to_decompress_past_limit() -> std::io::Result<()> {
+        let mut data_url = DataUrl::from_text(&"a".repeat(1024));
+        data_url.compress(ContentEncoding::Gzip)?;
+
+        assert!(data_url.decompress_with_limit(1023).is_err());
+        assert_eq!(data_url.content_encoding(), Some(ContentEncoding::Gzip));
+
+        data_url.decompress_with_limit(1024)?;
+
+        assert_eq!(data_url.data().len(), 1024);
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_apply_max_decoded_len_to_payload_decompressed_while_parsing() -> std::io::Result<()> {
+        let mut data_url = DataUrl::from_text(&"a".repeat(1024));
+        data_url.compress(ContentEncoding::Gzip)?;
+        let input: String = data_url.to_string();
+        let options = ParseOptions {
+            max_decoded_len: Some(1023),
+            decompress: true,
+            ..ParseOptions::default()
+        };
+
+        assert!(matches!(
+            DataUrl::parse_with_options(&input, &options),
+            Err(DataUrlParseError::LimitExceeded)
+        ));
+        assert!(matches!(
+            DataUrl::parse_lossless_with_options(&input, &options),
+            Err(DataUrlParseError::LimitExceeded)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_error_out_if_payload_cannot_be_decompressed_while_parsing() {
+        let options = ParseOptions {
+            decompress: true,
+            ..ParseOptions::default()
+        };
+
+        assert!(matches!(
+            DataUrl::parse_with_options("data:;content-encoding=gzip,a", &options),
+            Err(DataUrlParseError::DecompressionError)
+        ));
+    }
+}
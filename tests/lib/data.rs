@@ -0,0 +1,63 @@
+//  ██████╗  █████╗ ███████╗███████╗██╗███╗   ██╗ ██████╗
+//  ██╔══██╗██╔══██╗██╔════╝██╔════╝██║████╗  ██║██╔════╝
+//  ██████╔╝███████║███████╗███████╗██║██╔██╗ ██║██║  ███╗
+//  ██╔═══╝ ██╔══██║╚════██║╚════██║██║██║╚██╗██║██║   ██║
+//  ██║     ██║  ██║███████║███████║██║██║ ╚████║╚██████╔╝
+//  ╚═╝     ╚═╝  ╚═╝╚══════╝╚══════╝╚═╝╚═╝  ╚═══╝ ╚═════╝
+
+#[cfg(test)]
+mod passing {
+    use dataurl::{Bytes, DataUrl, DataUrlParseError};
+
+    #[test]
+    fn must_be_empty_by_default() {
+        let data_url = DataUrl::new();
+
+        assert!(data_url.data().is_empty());
+    }
+
+    #[test]
+    fn must_be_possible_to_set_by_copying() {
+        let mut data_url = DataUrl::new();
+        data_url.set_data(b"Hi");
+
+        assert_eq!(data_url.data(), b"Hi");
+    }
+
+    #[test]
+    fn must_take_over_vector_without_copying_it() {
+        let data: Vec<u8> = b"Hello".to_vec();
+        let pointer: *const u8 = data.as_ptr();
+        let mut data_url = DataUrl::new();
+        data_url.set_data_owned(data);
+
+        assert_eq!(data_url.data().as_ptr(), pointer);
+        let data: Vec<u8> = data_url.into_data();
+
+        assert_eq!(data.as_ptr(), pointer);
+    }
+
+    #[test]
+    fn must_share_payload_between_clones() -> Result<(), DataUrlParseError> {
+        let data_url = DataUrl::parse("data:;base64,SGVsbG8=")?;
+        let clone = data_url.clone();
+        let bytes: Bytes = data_url.data_bytes();
+
+        assert_eq!(clone.data().as_ptr(), data_url.data().as_ptr());
+        assert_eq!(bytes.as_ptr(), data_url.data().as_ptr());
+        assert_eq!(bytes, "Hello");
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_copy_shared_payload_when_turned_into_vector() -> Result<(), DataUrlParseError> {
+        let data_url = DataUrl::parse("data:,Hello")?;
+        let clone = data_url.clone();
+
+        assert_eq!(data_url.into_data(), b"Hello");
+        assert_eq!(clone.data(), b"Hello");
+
+        Ok(())
+    }
+}
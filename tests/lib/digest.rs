@@ -0,0 +1,42 @@
+//  ██████╗  █████╗ ███████╗███████╗██╗███╗   ██╗ ██████╗
+//  ██╔══██╗██╔══██╗██╔════╝██╔════╝██║████╗  ██║██╔════╝
+//  ██████╔╝███████║███████╗███████╗██║██╔██╗ ██║██║  ███╗
+//  ██╔═══╝ ██╔══██║╚════██║╚════██║██║██║╚██╗██║██║   ██║
+//  ██║     ██║  ██║███████║███████║██║██║ ╚████║╚██████╔╝
+//  ╚═╝     ╚═╝  ╚═╝╚══════╝╚══════╝╚═╝╚═╝  ╚═══╝ ╚═════╝
+
+#[cfg(test)]
+mod passing {
+    use dataurl::{Algorithm, DataUrl, DataUrlParseError};
+
+    #[test]
+    fn must_compute_sri_token_of_empty_payload() {
+        let data_url = DataUrl::new();
+
+        assert_eq!(
+            data_url.digest(Algorithm::Sha256),
+            "sha256-47DEQpj8HBSa+/TImW+5JCeuQeRkm5NMpJWZG3hSuFU="
+        );
+    }
+
+    #[test]
+    fn must_compute_sri_token_of_decoded_payload() -> Result<(), DataUrlParseError> {
+        let a: DataUrl = DataUrl::parse("data:text/javascript,alert(1)")?;
+        let b: DataUrl = DataUrl::parse("data:text/javascript;base64,YWxlcnQoMSk=")?;
+
+        assert_eq!(
+            a.digest(Algorithm::Sha384),
+            "sha384-HT2E9NfWiuQ/w1PRai+hTyqW16NIoCGA/m8VQDUopfAtcz6YQjtsMmQd5uRbVDpW"
+        );
+        assert_eq!(a.digest(Algorithm::Sha384), b.digest(Algorithm::Sha384));
+        assert!(a.digest(Algorithm::Sha512).starts_with("sha512-"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_recognize_algorithm_names() {
+        assert_eq!(Algorithm::from_name("SHA384"), Some(Algorithm::Sha384));
+        assert_eq!(Algorithm::from_name("md5"), None);
+    }
+}
@@ -0,0 +1,21 @@
+//  ██████╗  █████╗ ███████╗███████╗██╗███╗   ██╗ ██████╗
+//  ██╔══██╗██╔══██╗██╔════╝██╔════╝██║████╗  ██║██╔════╝
+//  ██████╔╝███████║███████╗███████╗██║██╔██╗ ██║██║  ███╗
+//  ██╔═══╝ ██╔══██║╚════██║╚════██║██║██║╚██╗██║██║   ██║
+//  ██║     ██║  ██║███████║███████║██║██║ ╚████║╚██████╔╝
+//  ╚═╝     ╚═╝  ╚═╝╚══════╝╚══════╝╚═╝╚═╝  ╚═══╝ ╚═════╝
+
+#[cfg(test)]
+mod passing {
+    use dataurl::DataUrl;
+
+    #[test]
+    fn must_wrap_bytes_without_setting_any_parameters() {
+        let data_url = DataUrl::from_bytes(&[0, 1, 2]);
+
+        assert_eq!(data_url.media_type_no_default(), None);
+        assert_eq!(data_url.charset_no_default(), None);
+        assert_eq!(data_url.data(), [0, 1, 2]);
+        assert_eq!(data_url.to_string(), "data:,%00%01%02");
+    }
+}
@@ -0,0 +1,59 @@
+//  ██████╗  █████╗ ███████╗███████╗██╗███╗   ██╗ ██████╗
+//  ██╔══██╗██╔══██╗██╔════╝██╔════╝██║████╗  ██║██╔════╝
+//  ██████╔╝███████║███████╗███████╗██║██╔██╗ ██║██║  ███╗
+//  ██╔═══╝ ██╔══██║╚════██║╚════██║██║██║╚██╗██║██║   ██║
+//  ██║     ██║  ██║███████║███████║██║██║ ╚████║╚██████╔╝
+//  ╚═╝     ╚═╝  ╚═╝╚══════╝╚══════╝╚═╝╚═╝  ╚═══╝ ╚═════╝
+
+#[cfg(test)]
+mod passing {
+    use dataurl::DataUrl;
+    use std::io;
+
+    #[test]
+    fn must_sniff_png_image() -> io::Result<()> {
+        let data_url = DataUrl::from_file(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/_data_/lizard.png"
+        ))?;
+
+        assert_eq!(data_url.media_type(), "image/png");
+        assert!(data_url.is_base64_encoded());
+        assert!(data_url
+            .to_string()
+            .starts_with("data:image/png;base64,iVBORw0KGgo"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_treat_unknown_utf8_file_as_plain_text() -> io::Result<()> {
+        let data_url = DataUrl::from_file(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/_data_/text-file.txt"
+        ))?;
+
+        assert_eq!(data_url.media_type_no_default(), None);
+        assert!(!data_url.is_base64_encoded());
+        assert_eq!(data_url.text(), "some content\n");
+
+        Ok(())
+    }
+}
+
+//  ███████╗ █████╗ ██╗██╗     ██╗███╗   ██╗ ██████╗
+//  ██╔════╝██╔══██╗██║██║     ██║████╗  ██║██╔════╝
+//  █████╗  ███████║██║██║     ██║██╔██╗ ██║██║  ███╗
+//  ██╔══╝  ██╔══██║██║██║     ██║██║╚██╗██║██║   ██║
+//  ██║     ██║  ██║██║███████╗██║██║ ╚████║╚██████╔╝
+//  ╚═╝     ╚═╝  ╚═╝╚═╝╚══════╝╚═╝╚═╝  ╚═══╝ ╚═════╝
+
+#[cfg(test)]
+mod failing {
+    use dataurl::DataUrl;
+
+    #[test]
+    fn must_return_error_if_file_does_not_exist() {
+        assert!(DataUrl::from_file("_data_/does-not-exist").is_err());
+    }
+}
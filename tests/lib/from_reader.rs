@@ -0,0 +1,32 @@
+//  ██████╗  █████╗ ███████╗███████╗██╗███╗   ██╗ ██████╗
+//  ██╔══██╗██╔══██╗██╔════╝██╔════╝██║████╗  ██║██╔════╝
+//  ██████╔╝███████║███████╗███████╗██║██╔██╗ ██║██║  ███╗
+//  ██╔═══╝ ██╔══██║╚════██║╚════██║██║██║╚██╗██║██║   ██║
+//  ██║     ██║  ██║███████║███████║██║██║ ╚████║╚██████╔╝
+//  ╚═╝     ╚═╝  ╚═╝╚══════╝╚══════╝╚═╝╚═╝  ╚═══╝ ╚═════╝
+
+#[cfg(test)]
+mod passing {
+    use dataurl::DataUrl;
+    use std::io;
+
+    #[test]
+    fn must_read_payload_and_sniff_media_type() -> io::Result<()> {
+        let data_url = DataUrl::from_reader(&b"GIF89a"[..])?;
+
+        assert_eq!(data_url.media_type(), "image/gif");
+        assert!(data_url.is_base64_encoded());
+        assert_eq!(data_url.to_string(), "data:image/gif;base64,R0lGODlh");
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_read_text_payload() -> io::Result<()> {
+        let data_url = DataUrl::from_reader(&b"Hello"[..])?;
+
+        assert_eq!(data_url.to_string(), "data:,Hello");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,132 @@
+//  ██████╗  █████╗ ███████╗███████╗██╗███╗   ██╗ ██████╗
+//  ██╔══██╗██╔══██╗██╔════╝██╔════╝██║████╗  ██║██╔════╝
+//  ██████╔╝███████║███████╗███████╗██║██╔██╗ ██║██║  ███╗
+//  ██╔═══╝ ██╔══██║╚════██║╚════██║██║██║╚██╗██║██║   ██║
+//  ██║     ██║  ██║███████║███████║██║██║ ╚████║╚██████╔╝
+//  ╚═╝     ╚═╝  ╚═╝╚══════╝╚══════╝╚═╝╚═╝  ╚═══╝ ╚═════╝
+
+#[cfg(test)]
+mod passing {
+    use dataurl::{Bytes, ContentEncoding, DataUrl};
+    use http::Response;
+
+    #[test]
+    fn must_take_media_type_and_charset_from_content_type() {
+        let response = Response::builder()
+            .header("Content-Type", "Text/HTML; charset=\"utf-8\"")
+            .body("<b>hi</b>")
+            .unwrap();
+        let data_url = DataUrl::from_response(&response);
+
+        assert_eq!(data_url.media_type(), "text/html");
+        assert_eq!(data_url.charset(), "UTF-8");
+        assert!(!data_url.is_base64_encoded());
+        assert_eq!(
+            data_url.to_string(),
+            "data:text/html;charset=UTF-8,%3Cb%3Ehi%3C%2Fb%3E"
+        );
+    }
+
+    #[test]
+    fn must_keep_other_content_type_parameters() {
+        let response = Response::builder()
+            .header(
+                "Content-Type",
+                "text/plain; format=flowed; title=\"a \\\"b\\\"\"",
+            )
+            .body("a")
+            .unwrap();
+        let data_url = DataUrl::from_response(&response);
+
+        assert_eq!(data_url.parameter("format"), Some("flowed"));
+        assert_eq!(data_url.parameter("title"), Some("a \"b\""));
+    }
+
+    #[test]
+    fn must_base64_encode_binary_body() {
+        let response = Response::builder()
+            .header("Content-Type", "image/gif")
+            .body(b"GIF89a".to_vec())
+            .unwrap();
+
+        assert_eq!(
+            DataUrl::from_response(&response).to_string(),
+            "data:image/gif;base64,R0lGODlh"
+        );
+    }
+
+    #[test]
+    fn must_take_content_encoding_into_account() {
+        let response = Response::builder()
+            .header("Content-Type", "text/css")
+            .header("Content-Encoding", "br")
+            .body(vec![1, 2, 3])
+            .unwrap();
+        let data_url = DataUrl::from_response(&response);
+
+        assert_eq!(data_url.content_encoding(), Some(ContentEncoding::Brotli));
+        assert!(data_url.is_base64_encoded());
+    }
+
+    #[test]
+    fn must_keep_unknown_content_encoding() {
+        let response = Response::builder()
+            .header("Content-Type", "text/css")
+            .header("Content-Encoding", "zstd")
+            .body(vec![1, 2, 3])
+            .unwrap();
+        let data_url = DataUrl::from_response(&response);
+
+        assert_eq!(data_url.content_encoding(), None);
+        assert_eq!(data_url.parameter("content-encoding"), Some("zstd"));
+        assert_eq!(
+            data_url.to_string(),
+            "data:text/css;content-encoding=zstd;base64,AQID"
+        );
+    }
+
+    #[test]
+    fn must_fall_back_to_defaults_without_content_type() {
+        let response = Response::new("Hello");
+
+        assert_eq!(DataUrl::from_response(&response).to_string(), "data:,Hello");
+    }
+
+    #[test]
+    fn must_take_over_bytes_body_without_copying_it() {
+        let body: Bytes = Bytes::from_static(b"Hello");
+        let response = Response::builder()
+            .header("Content-Type", "text/plain")
+            .body(body.clone())
+            .unwrap();
+        let data_url: DataUrl = response.into();
+
+        assert_eq!(data_url.to_string(), "data:text/plain,Hello");
+        assert_eq!(data_url.data().as_ptr(), body.as_ptr());
+    }
+}
+
+//  ███████╗ █████╗ ██╗██╗     ██╗███╗   ██╗ ██████╗
+//  ██╔════╝██╔══██╗██║██║     ██║████╗  ██║██╔════╝
+//  █████╗  ███████║██║██║     ██║██╔██╗ ██║██║  ███╗
+//  ██╔══╝  ██╔══██║██║██║     ██║██║╚██╗██║██║   ██║
+//  ██║     ██║  ██║██║███████╗██║██║ ╚████║╚██████╔╝
+//  ╚═╝     ╚═╝  ╚═╝╚═╝╚══════╝╚═╝╚═╝  ╚═══╝ ╚═════╝
+
+#[cfg(test)]
+mod failing {
+    use dataurl::DataUrl;
+    use http::Response;
+
+    #[test]
+    fn must_ignore_invalid_media_type_and_charset() {
+        let response = Response::builder()
+            .header("Content-Type", "html; charset=nonsense")
+            .body("a")
+            .unwrap();
+        let data_url = DataUrl::from_response(&response);
+
+        assert_eq!(data_url.media_type_no_default(), None);
+        assert_eq!(data_url.charset_no_default(), None);
+    }
+}
@@ -0,0 +1,31 @@
+//  ██████╗  █████╗ ███████╗███████╗██╗███╗   ██╗ ██████╗
+//  ██╔══██╗██╔══██╗██╔════╝██╔════╝██║████╗  ██║██╔════╝
+//  ██████╔╝███████║███████╗███████╗██║██╔██╗ ██║██║  ███╗
+//  ██╔═══╝ ██╔══██║╚════██║╚════██║██║██║╚██╗██║██║   ██║
+//  ██║     ██║  ██║███████║███████║██║██║ ╚████║╚██████╔╝
+//  ╚═╝     ╚═╝  ╚═╝╚══════╝╚══════╝╚═╝╚═╝  ╚═══╝ ╚═════╝
+
+#[cfg(test)]
+mod passing {
+    use dataurl::DataUrl;
+
+    #[test]
+    fn must_not_set_charset_for_ascii_text() {
+        let data_url = DataUrl::from_text("Hello, World!");
+
+        assert_eq!(data_url.charset_no_default(), None);
+        assert_eq!(data_url.to_string(), "data:,Hello%2C%20World%21");
+    }
+
+    #[test]
+    fn must_set_utf8_charset_for_non_ascii_text() {
+        let data_url = DataUrl::from_text("☀️");
+
+        assert_eq!(data_url.charset(), "UTF-8");
+        assert_eq!(data_url.text(), "☀️");
+        assert_eq!(
+            data_url.to_string(),
+            "data:;charset=UTF-8,%E2%98%80%EF%B8%8F"
+        );
+    }
+}
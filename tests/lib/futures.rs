@@ -0,0 +1,156 @@
+//  ██████╗  █████╗ ███████╗███████╗██╗███╗   ██╗ ██████╗
+//  ██╔══██╗██╔══██╗██╔════╝██╔════╝██║████╗  ██║██╔════╝
+//  ██████╔╝███████║███████╗███████╗██║██╔██╗ ██║██║  ███╗
+//  ██╔═══╝ ██╔══██║╚════██║╚════██║██║██║╚██╗██║██║   ██║
+//  ██║     ██║  ██║███████║███████║██║██║ ╚████║╚██████╔╝
+//  ╚═╝     ╚═╝  ╚═╝╚══════╝╚══════╝╚═╝╚═╝  ╚═══╝ ╚═════╝
+
+#[cfg(test)]
+mod passing {
+    use dataurl::{DataUrl, ParseOptions};
+    use futures_executor::block_on;
+    use futures_util::io::AsyncReadExt;
+    use std::io;
+
+    #[test]
+    fn must_read_payload_and_sniff_media_type() -> io::Result<()> {
+        let data_url = block_on(dataurl::futures::from_reader(&b"GIF89a"[..]))?;
+
+        assert_eq!(data_url.to_string(), "data:image/gif;base64,R0lGODlh");
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_parse_data_url_read_from_reader() -> io::Result<()> {
+        let data_url = block_on(dataurl::futures::parse_reader(
+            &b"data:text/html,%3Cb%3E"[..],
+        ))?;
+
+        assert_eq!(data_url.media_type(), "text/html");
+        assert_eq!(data_url.text(), "<b>");
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_write_data_url_and_its_data() -> io::Result<()> {
+        let data_url = DataUrl::parse("data:;base64,SGk=").unwrap();
+        let mut url: Vec<u8> = Vec::new();
+        let mut data: Vec<u8> = Vec::new();
+
+        block_on(dataurl::futures::write_to(&data_url, &mut url))?;
+        block_on(dataurl::futures::write_data_to(&data_url, &mut data))?;
+
+        assert_eq!(url, b"data:;base64,SGk=");
+        assert_eq!(data, b"Hi");
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_parse_the_same_however_input_is_split() -> io::Result<()> {
+        let input: &[u8] = b" data:text/plain,Hello%2C\t%20W%6frld\xff? #frag%20 \n";
+        let expected: DataUrl = DataUrl::parse(&String::from_utf8_lossy(input)).unwrap();
+
+        for i in 0..=input.len() {
+            let data_url = block_on(dataurl::futures::parse_reader(
+                (&input[..i]).chain(&input[i..]),
+            ))?;
+
+            assert_eq!(data_url.to_string(), expected.to_string());
+            assert_eq!(data_url.data(), expected.data());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_decode_payload_spanning_many_chunks() -> io::Result<()> {
+        let data: Vec<u8> = (0..100_000).map(|i| (i % 251) as u8).collect();
+        let input: String = DataUrl::from_bytes(&data).to_string();
+        let options: ParseOptions = ParseOptions {
+            max_decoded_len: Some(data.len()),
+            ..ParseOptions::default()
+        };
+
+        let data_url = block_on(dataurl::futures::parse_reader_with_options(
+            input.as_bytes(),
+            &options,
+        ))?;
+
+        assert_eq!(data_url.data(), &data[..]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_write_data_url_spanning_many_chunks() -> io::Result<()> {
+        let data: Vec<u8> = (0..100_000).map(|i| (i % 251) as u8).collect();
+        let mut data_url: DataUrl = DataUrl::from_bytes(&data);
+        data_url.set_fragment(Some("end".to_string()));
+        let mut url: Vec<u8> = Vec::new();
+
+        block_on(dataurl::futures::write_to(&data_url, &mut url))?;
+
+        assert_eq!(url, data_url.to_string().as_bytes());
+
+        Ok(())
+    }
+}
+
+//  ███████╗ █████╗ ██╗██╗     ██╗███╗   ██╗ ██████╗
+//  ██╔════╝██╔══██╗██║██║     ██║████╗  ██║██╔════╝
+//  █████╗  ███████║██║██║     ██║██╔██╗ ██║██║  ███╗
+//  ██╔══╝  ██╔══██║██║██║     ██║██║╚██╗██║██║   ██║
+//  ██║     ██║  ██║██║███████╗██║██║ ╚████║╚██████╔╝
+//  ╚═╝     ╚═╝  ╚═╝╚═╝╚══════╝╚═╝╚═╝  ╚═══╝ ╚═════╝
+
+#[cfg(test)]
+mod failing {
+    use dataurl::ParseOptions;
+    use futures_executor::block_on;
+    use std::io;
+
+    #[test]
+    fn must_return_invalid_data_error_if_not_a_data_url() {
+        let result = block_on(dataurl::futures::parse_reader(&b"not a data URL"[..]));
+
+        assert_eq!(result.err().unwrap().kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn must_stop_reading_once_over_input_limit() {
+        let options: ParseOptions = ParseOptions {
+            max_input_len: Some(16),
+            ..ParseOptions::default()
+        };
+        let result = block_on(dataurl::futures::parse_reader_with_options(
+            &b"data:,Hello%2C%20World!"[..],
+            &options,
+        ));
+
+        assert_eq!(result.err().unwrap().kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn must_stop_reading_once_over_decoded_limit() {
+        let options: ParseOptions = ParseOptions {
+            max_decoded_len: Some(4),
+            ..ParseOptions::default()
+        };
+        let result = block_on(dataurl::futures::parse_reader_with_options(
+            &b"data:;base64,SGVsbG8sIFdvcmxkIQ=="[..],
+            &options,
+        ));
+
+        assert_eq!(result.err().unwrap().kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn must_not_read_more_data_than_limit_allows() {
+        let result = block_on(dataurl::futures::from_reader_with_limit(&b"GIF89a"[..], 4));
+
+        assert_eq!(result.err().unwrap().kind(), io::ErrorKind::InvalidData);
+    }
+}
@@ -0,0 +1,140 @@
+//  ██████╗  █████╗ ███████╗███████╗██╗███╗   ██╗ ██████╗
+//  ██╔══██╗██╔══██╗██╔════╝██╔════╝██║████╗  ██║██╔════╝
+//  ██████╔╝███████║███████╗███████╗██║██╔██╗ ██║██║  ███╗
+//  ██╔═══╝ ██╔══██║╚════██║╚════██║██║██║╚██╗██║██║   ██║
+//  ██║     ██║  ██║███████║███████║██║██║ ╚████║╚██████╔╝
+//  ╚═╝     ╚═╝  ╚═╝╚══════╝╚══════╝╚═╝╚═╝  ╚═══╝ ╚═════╝
+
+#[cfg(test)]
+mod passing {
+    use dataurl::{DataUrl, DataUrlParseError};
+
+    #[test]
+    fn must_read_png_dimensions() -> Result<(), DataUrlParseError> {
+        let data_url: DataUrl = DataUrl::parse("data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAYAAAAfFcSJAAAADUlEQVR42mNkYPhfDwAChwGA60e6kgAAAABJRU5ErkJggg==")?;
+
+        assert_eq!(data_url.image_dimensions(), Some((1, 1)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_read_gif_dimensions() -> Result<(), DataUrlParseError> {
+        let data_url: DataUrl = DataUrl::parse(
+            "data:image/gif;base64,R0lGODlhAQABAIAAAAAAAP///yH5BAEAAAAALAAAAAABAAEAAAIBRAA7",
+        )?;
+
+        assert_eq!(data_url.image_dimensions(), Some((1, 1)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_read_jpeg_dimensions_past_other_segments() {
+        let data_url: DataUrl = DataUrl::from_bytes(&[
+            0xff, 0xd8, // SOI
+            0xff, 0xe0, 0x00, 0x04, 0x00, 0x00, // APP0
+            0xff, 0xc2, 0x00, 0x0b, 0x08, 0x01, 0x2c, 0x02, 0x58, 0x01, 0x01, 0x11,
+            0x00, // SOF2
+        ]);
+
+        assert_eq!(data_url.image_dimensions(), Some((600, 300)));
+    }
+
+    #[test]
+    fn must_read_lossy_webp_dimensions() {
+        let mut data: Vec<u8> = b"RIFF\x00\x00\x00\x00WEBPVP8 \x00\x00\x00\x00".to_vec();
+        data.extend_from_slice(&[0x00, 0x00, 0x00, 0x9d, 0x01, 0x2a, 0x40, 0x01, 0xf0, 0x00]);
+        let data_url: DataUrl = DataUrl::from_bytes(&data);
+
+        assert_eq!(data_url.image_dimensions(), Some((320, 240)));
+    }
+
+    #[test]
+    fn must_read_lossless_webp_dimensions() {
+        let mut data: Vec<u8> = b"RIFF\x00\x00\x00\x00WEBPVP8L\x00\x00\x00\x00".to_vec();
+        // 5x7, both stored minus one, 14 bits each
+        data.push(0x2f);
+        data.extend_from_slice(&(4u32 | 6 << 14).to_le_bytes());
+        let data_url: DataUrl = DataUrl::from_bytes(&data);
+
+        assert_eq!(data_url.image_dimensions(), Some((5, 7)));
+    }
+
+    #[test]
+    fn must_read_extended_webp_dimensions() {
+        let mut data: Vec<u8> = b"RIFF\x00\x00\x00\x00WEBPVP8X\x0a\x00\x00\x00".to_vec();
+        data.extend_from_slice(&[0x00, 0x00, 0x00, 0x00, 0xff, 0x0f, 0x00, 0x01, 0x00, 0x00]);
+        let data_url: DataUrl = DataUrl::from_bytes(&data);
+
+        assert_eq!(data_url.image_dimensions(), Some((4096, 2)));
+    }
+
+    #[test]
+    fn must_read_svg_dimensions() -> Result<(), DataUrlParseError> {
+        let data_url: DataUrl = DataUrl::parse(
+            "data:image/svg+xml,<?xml version='1.0'?><svg stroke-width='3' width=\"120px\" height = '80.4'></svg>",
+        )?;
+
+        assert_eq!(data_url.image_dimensions(), Some((120, 80)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_fall_back_to_svg_view_box() -> Result<(), DataUrlParseError> {
+        let data_url: DataUrl = DataUrl::parse(
+            "data:image/svg+xml,%EF%BB%BF <svg width='100%25' viewBox='0,0 24 16'></svg>",
+        )?;
+
+        assert_eq!(data_url.image_dimensions(), Some((24, 16)));
+
+        Ok(())
+    }
+}
+
+//  ███████╗ █████╗ ██╗██╗     ██╗███╗   ██╗ ██████╗
+//  ██╔════╝██╔══██╗██║██║     ██║████╗  ██║██╔════╝
+//  █████╗  ███████║██║██║     ██║██╔██╗ ██║██║  ███╗
+//  ██╔══╝  ██╔══██║██║██║     ██║██║╚██╗██║██║   ██║
+//  ██║     ██║  ██║██║███████╗██║██║ ╚████║╚██████╔╝
+//  ╚═╝     ╚═╝  ╚═╝╚═╝╚══════╝╚═╝╚═╝  ╚═══╝ ╚═════╝
+
+#[cfg(test)]
+mod failing {
+    use dataurl::{DataUrl, DataUrlParseError};
+
+    #[test]
+    fn must_not_read_dimensions_of_non_images() -> Result<(), DataUrlParseError> {
+        let data_url: DataUrl = DataUrl::parse("data:image/png,not%20really")?;
+
+        assert_eq!(data_url.image_dimensions(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_not_read_dimensions_of_truncated_images() {
+        let data_url: DataUrl =
+            DataUrl::from_bytes(b"\x89PNG\r\n\x1a\n\x00\x00\x00\x0dIHDR\x00\x00");
+
+        assert_eq!(data_url.image_dimensions(), None);
+    }
+
+    #[test]
+    fn must_not_read_dimensions_of_jpeg_without_frame() {
+        let data_url: DataUrl = DataUrl::from_bytes(&[0xff, 0xd8, 0xff, 0xd9]);
+
+        assert_eq!(data_url.image_dimensions(), None);
+    }
+
+    #[test]
+    fn must_not_read_dimensions_of_svg_with_relative_size() -> Result<(), DataUrlParseError> {
+        let data_url: DataUrl =
+            DataUrl::parse("data:image/svg+xml,<svg width='2em' height='1em'/>")?;
+
+        assert_eq!(data_url.image_dimensions(), None);
+
+        Ok(())
+    }
+}
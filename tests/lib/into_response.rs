@@ -0,0 +1,92 @@
+//  ██████╗  █████╗ ███████╗███████╗██╗███╗   ██╗ ██████╗
+//  ██╔══██╗██╔══██╗██╔════╝██╔════╝██║████╗  ██║██╔════╝
+//  ██████╔╝███████║███████╗███████╗██║██╔██╗ ██║██║  ███╗
+//  ██╔═══╝ ██╔══██║╚════██║╚════██║██║██║╚██╗██║██║   ██║
+//  ██║     ██║  ██║███████║███████║██║██║ ╚████║╚██████╔╝
+//  ╚═╝     ╚═╝  ╚═╝╚══════╝╚══════╝╚═╝╚═╝  ╚═══╝ ╚═════╝
+
+#[cfg(test)]
+mod passing {
+    use dataurl::{Bytes, DataUrl, DataUrlParseError};
+    use http::{Response, StatusCode};
+
+    #[test]
+    fn must_default_to_text_plain_us_ascii() -> Result<(), DataUrlParseError> {
+        let response: Response<Vec<u8>> = (&DataUrl::parse("data:,Hello")?).into();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers()["Content-Type"],
+            "text/plain;charset=US-ASCII"
+        );
+        assert_eq!(response.headers()["Content-Length"], "5");
+        assert_eq!(response.body(), b"Hello");
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_only_mention_charset_if_set() -> Result<(), DataUrlParseError> {
+        let response: Response<Vec<u8>> =
+            (&DataUrl::parse("data:image/gif;base64,R0lGODlh")?).into();
+
+        assert_eq!(response.headers()["Content-Type"], "image/gif");
+        assert_eq!(response.body(), b"GIF89a");
+
+        let response: Response<Vec<u8>> =
+            (&DataUrl::parse("data:text/html;charset=utf-8,<b>")?).into();
+
+        assert_eq!(
+            response.headers()["Content-Type"],
+            "text/html;charset=UTF-8"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_quote_parameters_when_needed() -> Result<(), DataUrlParseError> {
+        let response: Response<Vec<u8>> =
+            (&DataUrl::parse("data:text/plain;format=flowed;title=a%20b,a")?).into();
+
+        assert_eq!(
+            response.headers()["Content-Type"],
+            "text/plain;format=flowed;title=\"a b\""
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_set_content_encoding() -> Result<(), DataUrlParseError> {
+        let response: Response<Vec<u8>> =
+            (&DataUrl::parse("data:text/css;content-encoding=gzip;base64,AAAA")?).into();
+
+        assert_eq!(response.headers()["Content-Encoding"], "gzip");
+        assert_eq!(response.headers()["Content-Length"], "3");
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_set_unknown_content_encoding() -> Result<(), DataUrlParseError> {
+        let response: Response<Vec<u8>> =
+            (&DataUrl::parse("data:text/css;content-encoding=zstd;base64,AAAA")?).into();
+
+        assert_eq!(response.headers()["Content-Type"], "text/css");
+        assert_eq!(response.headers()["Content-Encoding"], "zstd");
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_share_payload_with_bytes_response() -> Result<(), DataUrlParseError> {
+        let data_url: DataUrl = DataUrl::parse("data:,Hello")?;
+        let response: Response<Bytes> = (&data_url).into();
+
+        assert_eq!(response.headers()["Content-Length"], "5");
+        assert_eq!(response.body().as_ptr(), data_url.data().as_ptr());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,94 @@
+//  ██████╗  █████╗ ███████╗███████╗██╗███╗   ██╗ ██████╗
+//  ██╔══██╗██╔══██╗██╔════╝██╔════╝██║████╗  ██║██╔════╝
+//  ██████╔╝███████║███████╗███████╗██║██╔██╗ ██║██║  ███╗
+//  ██╔═══╝ ██╔══██║╚════██║╚════██║██║██║╚██╗██║██║   ██║
+//  ██║     ██║  ██║███████║███████║██║██║ ╚████║╚██████╔╝
+//  ╚═╝     ╚═╝  ╚═╝╚══════╝╚══════╝╚═╝╚═╝  ╚═══╝ ╚═════╝
+
+#[cfg(test)]
+mod passing {
+    use dataurl::{DataUrl, DataUrlParseError};
+    use mime::Mime;
+
+    #[test]
+    fn must_convert_media_type_and_parameters() -> Result<(), DataUrlParseError> {
+        let data_url = DataUrl::parse("data:text/html;charset=utf-8;level=1,a")?;
+        let mime: Mime = data_url.mime();
+
+        assert_eq!(mime.essence_str(), "text/html");
+        assert_eq!(mime.get_param(mime::CHARSET).unwrap(), "utf-8");
+        assert_eq!(mime.get_param("level").unwrap(), "1");
+        assert_eq!(Mime::from(&data_url), mime);
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_default_to_text_plain_us_ascii() {
+        let mime: Mime = DataUrl::new().mime();
+
+        assert_eq!(mime.essence_str(), "text/plain");
+        assert_eq!(mime.get_param(mime::CHARSET).unwrap(), "us-ascii");
+    }
+
+    #[test]
+    fn must_quote_parameter_values_that_are_not_tokens() -> Result<(), DataUrlParseError> {
+        let data_url = DataUrl::parse("data:image/png;filename=a%20b.png;base64,")?;
+
+        assert_eq!(data_url.mime().get_param("filename").unwrap(), "a b.png");
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_set_media_type_and_replace_parameters() -> Result<(), DataUrlParseError> {
+        let mut data_url = DataUrl::parse("data:text/plain;charset=utf-8;filename=a.txt,a")?;
+        let mime: Mime = "text/css; charset=iso-8859-2; level=\"2\"".parse().unwrap();
+
+        assert!(data_url.set_mime(&mime));
+
+        assert_eq!(data_url.media_type(), "text/css");
+        assert_eq!(data_url.charset(), "ISO-8859-2");
+        assert_eq!(data_url.parameter("filename"), None);
+        assert_eq!(data_url.parameter("level"), Some("2"));
+        assert_eq!(
+            data_url.to_string(),
+            "data:text/css;charset=ISO-8859-2;level=2,a"
+        );
+
+        Ok(())
+    }
+}
+
+//  ███████╗ █████╗ ██╗██╗     ██╗███╗   ██╗ ██████╗
+//  ██╔════╝██╔══██╗██║██║     ██║████╗  ██║██╔════╝
+//  █████╗  ███████║██║██║     ██║██╔██╗ ██║██║  ███╗
+//  ██╔══╝  ██╔══██║██║██║     ██║██║╚██╗██║██║   ██║
+//  ██║     ██║  ██║██║███████╗██║██║ ╚████║╚██████╔╝
+//  ╚═╝     ╚═╝  ╚═╝╚═╝╚══════╝╚═╝╚═╝  ╚═══╝ ╚═════╝
+
+#[cfg(test)]
+mod failing {
+    use dataurl::DataUrl;
+    use mime::Mime;
+
+    #[test]
+    fn must_drop_parameters_mime_cannot_represent() {
+        let mut data_url = DataUrl::new();
+        data_url.set_media_type(Some("text/plain".to_string()));
+        data_url.set_parameter("title", Some("say \"hi\"".to_string()));
+
+        assert_eq!(data_url.mime(), mime::TEXT_PLAIN);
+    }
+
+    #[test]
+    fn must_report_invalid_charset() {
+        let mut data_url = DataUrl::new();
+        let mime: Mime = "text/plain; charset=nonsense".parse().unwrap();
+
+        assert!(!data_url.set_mime(&mime));
+
+        assert_eq!(data_url.media_type(), "text/plain");
+        assert_eq!(data_url.charset_no_default(), None);
+    }
+}
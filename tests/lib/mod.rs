@@ -1,15 +1,60 @@
 // Constructors
+mod builder;
+mod from_bytes;
+#[cfg(feature = "std")]
+mod from_file;
+#[cfg(feature = "std")]
+mod from_reader;
+#[cfg(feature = "http")]
+mod from_response;
+mod from_text;
 mod new;
 mod parse;
+mod parse_lossless;
+#[cfg(feature = "std")]
+mod parse_reader;
+mod parse_strict;
+mod parse_whatwg;
+mod parse_with_options;
 
 // Getters and setters
 mod charset;
 mod charset_no_default;
+mod data;
+#[cfg(feature = "digest")]
+mod digest;
 mod fragment;
+mod image_dimensions;
 mod media_type;
 mod media_type_no_default;
+#[cfg(feature = "mime")]
+mod mime;
+mod parameter;
+#[cfg(feature = "image")]
+mod reencode_image;
 mod text;
+mod to_canonical_string;
+mod to_font_face;
+mod to_html;
 mod to_string;
+mod to_string_with_options;
+
+// Serialization
+#[cfg(feature = "http")]
+mod into_response;
+#[cfg(feature = "std")]
+mod write_data_to;
+#[cfg(feature = "std")]
+mod write_to;
 
 // Misc.
 mod _misc_;
+mod canonicalize;
+#[cfg(feature = "compression")]
+mod compress;
+#[cfg(feature = "futures")]
+mod futures;
+mod multipart;
+mod round_trip;
+#[cfg(feature = "tokio")]
+mod tokio;
@@ -0,0 +1,197 @@
+//  ██████╗  █████╗ ███████╗███████╗██╗███╗   ██╗ ██████╗
+//  ██╔══██╗██╔══██╗██╔════╝██╔════╝██║████╗  ██║██╔════╝
+//  ██████╔╝███████║███████╗███████╗██║██╔██╗ ██║██║  ███╗
+//  ██╔═══╝ ██╔══██║╚════██║╚════██║██║██║╚██╗██║██║   ██║
+//  ██║     ██║  ██║███████║███████║██║██║ ╚████║╚██████╔╝
+//  ╚═╝     ╚═╝  ╚═╝╚══════╝╚══════╝╚═╝╚═╝  ╚═══╝ ╚═════╝
+
+#[cfg(test)]
+mod passing {
+    use base64::{engine::general_purpose::STANDARD, Engine as _};
+    use dataurl::{html_to_multipart, multipart_to_html, MultipartError};
+
+    #[test]
+    fn must_move_data_urls_into_parts() {
+        let message: String =
+            html_to_multipart(r#"<img src="data:image/png;base64,iVBORw0KGgo=" alt="">"#);
+
+        assert!(message
+            .starts_with("MIME-Version: 1.0\r\nContent-Type: multipart/related; boundary=\""));
+        assert!(message.contains(
+            "\r\nContent-Type: image/png\r\nContent-Transfer-Encoding: base64\r\nContent-ID: <"
+        ));
+        assert!(message.contains("\r\n\r\niVBORw0KGgo=\r\n"));
+        assert!(!message.contains("data:"));
+        assert!(message.ends_with("--\r\n"));
+    }
+
+    #[test]
+    fn must_share_one_part_between_identical_data_urls() {
+        let message: String = html_to_multipart(
+            "<img src=\"data:text/plain,a\"><img src='data:text/plain,a'><img src=data:text/plain,b>",
+        );
+
+        assert_eq!(message.matches("Content-ID:").count(), 2);
+    }
+
+    #[test]
+    fn must_leave_invalid_data_urls_alone() {
+        let message: String = html_to_multipart("<p>metadata: none</p>");
+
+        assert_eq!(message.matches("Content-ID:").count(), 0);
+    }
+
+    #[test]
+    fn must_round_trip() -> Result<(), MultipartError> {
+        let html: &str = r#"<style>p { background: url(data:image/gif;base64,R0lGODlhAQABAAAAACw=) }</style>
+<a href="data:text/plain;charset=UTF-8;base64,SGVsbG8=">Hello</a>"#;
+
+        assert_eq!(multipart_to_html(&html_to_multipart(html))?, html);
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_decode_quoted_printable_root_and_folded_headers() -> Result<(), MultipartError> {
+        let message: &str = "Content-Type: multipart/related;\n\tboundary=\"b\"\n\n--b\nContent-Type: text/html; charset=\"ISO-8859-1\"\nContent-Transfer-Encoding: quoted-printable\n\n<p>Caf=E9 <img src=3D\"cid:dot\"></p>=\n\n--b\nContent-Type: image/gif\nContent-ID: <dot>\nContent-Transfer-Encoding: base64\n\nR0lG\nODlh\n--b--\n";
+
+        assert_eq!(
+            multipart_to_html(message)?,
+            "<p>Café <img src=\"data:image/gif;base64,R0lGODlh\"></p>"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_use_start_parameter_to_find_root() -> Result<(), MultipartError> {
+        let message: &str = "Content-Type: multipart/related; boundary=b; start=\"<root>\"\r\n\r\n--b\r\nContent-Type: text/html\r\n\r\nNot this one\r\n--b\r\nContent-Type: text/html\r\nContent-ID: <root>\r\n\r\nThis one\r\n--b--\r\n";
+
+        assert_eq!(multipart_to_html(message)?, "This one");
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_return_body_of_plain_html_message() -> Result<(), MultipartError> {
+        assert_eq!(
+            multipart_to_html("Content-Type: text/html\r\n\r\n<p>Hi</p>")?,
+            "<p>Hi</p>"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_put_standard_base64_into_html() -> Result<(), MultipartError> {
+        let message: &str = "Content-Type: multipart/related; boundary=b\r\n\r\n\
+--b\r\n\
+Content-Type: text/html\r\n\r\n\
+<img src=\"cid:x\">\r\n\
+--b\r\n\
+Content-Type: image/png\r\n\
+Content-ID: <x>\r\n\
+Content-Transfer-Encoding: base64\r\n\r\n\
++/+/\r\n\
+--b--\r\n";
+        let html: String = multipart_to_html(message)?;
+        let payload: &str = html
+            .split("base64,")
+            .nth(1)
+            .unwrap()
+            .split('"')
+            .next()
+            .unwrap();
+
+        assert_eq!(STANDARD.decode(payload).unwrap(), [0xfb, 0xff, 0xbf]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_round_trip_quoted_urls_with_spaces_and_fragments() -> Result<(), MultipartError> {
+        let html: &str = "<img src=\"data:image/svg+xml,%3Csvg xmlns=%27http://www.w3.org/2000/svg%27/%3E#icon\" alt=\"\">";
+        let message: String = html_to_multipart(html);
+
+        assert_eq!(message.matches("Content-ID:").count(), 1);
+        assert_eq!(
+            multipart_to_html(&message)?,
+            "<img src=\"data:image/svg+xml;base64,PHN2ZyB4bWxucz0naHR0cDovL3d3dy53My5vcmcvMjAwMC9zdmcnLz4=#icon\" alt=\"\">"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_escape_parameter_values_in_headers() -> Result<(), MultipartError> {
+        let html: &str = "<a href='data:text/plain;name=%22a%5Cb%22,hi'>x</a>";
+        let message: String = html_to_multipart(html);
+
+        assert!(message.contains("\r\nContent-Type: text/plain; name=\"\\\"a\\\\b\\\"\"\r\n"));
+        assert_eq!(
+            multipart_to_html(&message)?,
+            "<a href='data:text/plain;name=%22a\\b%22;base64,aGk='>x</a>"
+        );
+
+        Ok(())
+    }
+}
+
+//  ███████╗ █████╗ ██╗██╗     ██╗███╗   ██╗ ██████╗
+//  ██╔════╝██╔══██╗██║██║     ██║████╗  ██║██╔════╝
+//  █████╗  ███████║██║██║     ██║██╔██╗ ██║██║  ███╗
+//  ██╔══╝  ██╔══██║██║██║     ██║██║╚██╗██║██║   ██║
+//  ██║     ██║  ██║██║███████╗██║██║ ╚████║╚██████╔╝
+//  ╚═╝     ╚═╝  ╚═╝╚═╝╚══════╝╚═╝╚═╝  ╚═══╝ ╚═════╝
+
+#[cfg(test)]
+mod failing {
+    use dataurl::{html_to_multipart, multipart_to_html, MultipartError};
+
+    #[test]
+    fn must_fail_without_boundary() {
+        assert_eq!(
+            multipart_to_html("Content-Type: multipart/related\r\n\r\n"),
+            Err(MultipartError::MissingBoundary)
+        );
+    }
+
+    #[test]
+    fn must_fail_without_closing_boundary() {
+        assert_eq!(
+            multipart_to_html(
+                "Content-Type: multipart/related; boundary=b\r\n\r\n--b\r\n\r\nHi\r\n"
+            ),
+            Err(MultipartError::MalformedMessage)
+        );
+    }
+
+    #[test]
+    fn must_fail_on_corrupt_base64_part() {
+        assert_eq!(
+            multipart_to_html(
+                "Content-Type: multipart/related; boundary=b\r\n\r\n--b\r\nContent-Type: text/html\r\n\r\n<img src=\"cid:x\">\r\n--b\r\nContent-Type: image/png\r\nContent-ID: <x>\r\nContent-Transfer-Encoding: base64\r\n\r\n!!!\r\n--b--\r\n"
+            ),
+            Err(MultipartError::MalformedMessage)
+        );
+    }
+
+    #[test]
+    fn must_fail_without_html_part() {
+        assert_eq!(
+            multipart_to_html(
+                "Content-Type: multipart/related; boundary=b\r\n\r\n--b\r\nContent-Type: text/plain\r\n\r\nHi\r\n--b--\r\n"
+            ),
+            Err(MultipartError::MissingHtmlPart)
+        );
+    }
+
+    #[test]
+    fn must_not_move_data_urls_with_line_breaks_in_parameters_into_headers() {
+        let html: &str = "<img src=\"data:image/png;name=%0D%0AContent-ID:%20%3Cevil%3E,abc\">";
+        let message: String = html_to_multipart(html);
+
+        assert!(!message.contains("<evil>"));
+        assert_eq!(message.matches("Content-ID:").count(), 0);
+    }
+}
@@ -0,0 +1,131 @@
+//  ██████╗  █████╗ ███████╗███████╗██╗███╗   ██╗ ██████╗
+//  ██╔══██╗██╔══██╗██╔════╝██╔════╝██║████╗  ██║██╔════╝
+//  ██████╔╝███████║███████╗███████╗██║██╔██╗ ██║██║  ███╗
+//  ██╔═══╝ ██╔══██║╚════██║╚════██║██║██║╚██╗██║██║   ██║
+//  ██║     ██║  ██║███████║███████║██║██║ ╚████║╚██████╔╝
+//  ╚═╝     ╚═╝  ╚═╝╚══════╝╚══════╝╚═╝╚═╝  ╚═══╝ ╚═════╝
+
+#[cfg(test)]
+mod passing {
+    use dataurl::{ContentEncoding, DataUrl, DataUrlParseError};
+
+    #[test]
+    fn must_keep_parameters_other_than_charset() -> Result<(), DataUrlParseError> {
+        let data_url = DataUrl::parse("data:text/css;Filename=x.css;charset=utf-8;level=1,a")?;
+
+        assert_eq!(data_url.parameter("filename"), Some("x.css"));
+        assert_eq!(data_url.parameter("LEVEL"), Some("1"));
+        assert_eq!(data_url.parameter("charset"), Some("UTF-8"));
+        assert_eq!(
+            data_url.parameters(),
+            [
+                ("filename".to_string(), "x.css".to_string()),
+                ("level".to_string(), "1".to_string())
+            ]
+        );
+        assert_eq!(
+            data_url.to_string(),
+            "data:text/css;charset=UTF-8;filename=x.css;level=1,a"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_percent_decode_parameter_values() -> Result<(), DataUrlParseError> {
+        let data_url = DataUrl::parse("data:text/plain;filename=a%20b%2Cc.txt,a")?;
+
+        assert_eq!(data_url.parameter("filename"), Some("a b,c.txt"));
+        assert_eq!(
+            data_url.to_string(),
+            "data:text/plain;filename=a%20b%2Cc.txt,a"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_be_possible_to_add_change_and_remove() {
+        let mut data_url = DataUrl::new();
+
+        assert!(data_url.set_parameter("filename", Some("a.txt".to_string())));
+        assert!(data_url.set_parameter("Filename", Some("b.txt".to_string())));
+        assert_eq!(data_url.to_string(), "data:;filename=b.txt,");
+
+        assert!(data_url.set_parameter("filename", None));
+        assert_eq!(data_url.parameter("filename"), None);
+        assert_eq!(data_url.to_string(), "data:,");
+    }
+
+    #[test]
+    fn must_delegate_charset_and_content_encoding() {
+        let mut data_url = DataUrl::new();
+
+        assert!(data_url.set_parameter("charset", Some("utf8".to_string())));
+        assert!(data_url.set_parameter("content-encoding", Some("gzip".to_string())));
+
+        assert_eq!(data_url.charset(), "UTF-8");
+        assert_eq!(data_url.parameter("content-encoding"), Some("gzip"));
+        assert!(data_url.parameters().is_empty());
+    }
+
+    #[test]
+    fn must_keep_lossless_serialization_of_other_parameters() -> Result<(), DataUrlParseError> {
+        let mut data_url = DataUrl::parse_lossless("data:Text/Plain;Foo=Bar;base64,YQ==")?;
+
+        assert!(data_url.set_parameter("filename", Some("a b".to_string())));
+
+        assert_eq!(
+            data_url.to_string(),
+            "data:Text/Plain;Foo=Bar;filename=a%20b;base64,YQ=="
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_keep_unknown_content_encoding_as_parameter() {
+        let mut data_url = DataUrl::new();
+
+        assert!(data_url.set_parameter("content-encoding", Some("zip".to_string())));
+        assert_eq!(data_url.content_encoding(), None);
+        assert_eq!(data_url.parameter("content-encoding"), Some("zip"));
+        assert_eq!(data_url.to_string(), "data:;content-encoding=zip,");
+
+        data_url.set_content_encoding(Some(ContentEncoding::Gzip));
+
+        assert_eq!(data_url.parameters(), []);
+        assert_eq!(data_url.to_string(), "data:;content-encoding=gzip,");
+    }
+}
+
+//  ███████╗ █████╗ ██╗██╗     ██╗███╗   ██╗ ██████╗
+//  ██╔════╝██╔══██╗██║██║     ██║████╗  ██║██╔════╝
+//  █████╗  ███████║██║██║     ██║██╔██╗ ██║██║  ███╗
+//  ██╔══╝  ██╔══██║██║██║     ██║██║╚██╗██║██║   ██║
+//  ██║     ██║  ██║██║███████╗██║██║ ╚████║╚██████╔╝
+//  ╚═╝     ╚═╝  ╚═╝╚═╝╚══════╝╚═╝╚═╝  ╚═══╝ ╚═════╝
+
+#[cfg(test)]
+mod failing {
+    use dataurl::{DataUrl, DataUrlParseError};
+
+    #[test]
+    fn must_ignore_parameters_after_base64() -> Result<(), DataUrlParseError> {
+        let data_url = DataUrl::parse("data:text/plain;base64;filename=x,YQ==")?;
+
+        assert!(data_url.parameters().is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_refuse_to_set_base64_or_nameless_parameter() {
+        let mut data_url = DataUrl::new();
+
+        assert!(!data_url.set_parameter("base64", Some("1".to_string())));
+        assert!(!data_url.set_parameter(" ", Some("1".to_string())));
+
+        assert_eq!(data_url.to_string(), "data:,");
+    }
+}
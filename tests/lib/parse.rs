@@ -18,6 +18,25 @@ mod passing {
         Ok(())
     }
 
+    #[test]
+    fn must_remove_tabs_and_newlines() -> Result<(), DataUrlParseError> {
+        let data_url: DataUrl = DataUrl::parse("data:text/\tplain,a\r\nb")?;
+
+        assert_eq!(data_url.media_type(), "text/plain");
+        assert_eq!(data_url.data(), b"ab");
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_accept_scheme_in_any_case() -> Result<(), DataUrlParseError> {
+        let data_url: DataUrl = DataUrl::parse("DaTa:,a")?;
+
+        assert_eq!(data_url.text(), "a");
+
+        Ok(())
+    }
+
     #[test]
     fn must_be_able_to_parse_url_with_no_media_type() -> Result<(), DataUrlParseError> {
         let data_url: DataUrl = DataUrl::parse("data:,Hello,%20World!")?;
@@ -167,18 +186,25 @@ mod failing {
 
     #[test]
     fn must_error_out_if_given_empty_string() -> Result<(), DataUrlParseError> {
-        match DataUrl::parse("") {
-            Ok(_data_url) => {
-                assert!(false);
-            }
-            Err(_data_url_parse_err) => {
-                assert!(true);
-            }
-        }
+        assert!(DataUrl::parse("").is_err());
 
         Ok(())
     }
 
+    #[test]
+    fn must_error_out_if_given_url_of_another_scheme() {
+        assert!(DataUrl::parse("http://example.com/,a").is_err());
+    }
+
+    #[test]
+    fn must_describe_what_went_wrong() {
+        let err: DataUrlParseError = DataUrl::parse("data:;base64,%%%").err().unwrap();
+
+        assert_eq!(err, DataUrlParseError::Base64DecodeError);
+        assert_eq!(format!("{:?}", err), "Base64DecodeError");
+        assert_eq!(err.to_string(), "unable to decode base64");
+    }
+
     #[test]
     fn must_treat_data_as_base64_unencoded_if_no_semicolon_before_base64(
     ) -> Result<(), DataUrlParseError> {
@@ -203,7 +229,7 @@ mod failing {
         assert_eq!(data_url.media_type(), "text/plain".to_string());
         assert_eq!(data_url.charset(), "US-ASCII".to_string());
         assert!(data_url.is_base64_encoded());
-        assert_eq!(data_url.data(), []);
+        assert_eq!(data_url.data(), [] as [u8; 0]);
         assert_eq!(data_url.fragment(), None);
 
         Ok(())
@@ -216,7 +242,7 @@ mod failing {
         assert_eq!(data_url.media_type(), "text/plain".to_string());
         assert_eq!(data_url.charset(), "US-ASCII".to_string());
         assert!(!data_url.is_base64_encoded());
-        assert_eq!(data_url.data(), []);
+        assert_eq!(data_url.data(), [] as [u8; 0]);
         assert_eq!(data_url.fragment(), None);
 
         Ok(())
@@ -0,0 +1,134 @@
+//  ██████╗  █████╗ ███████╗███████╗██╗███╗   ██╗ ██████╗
+//  ██╔══██╗██╔══██╗██╔════╝██╔════╝██║████╗  ██║██╔════╝
+//  ██████╔╝███████║███████╗███████╗██║██╔██╗ ██║██║  ███╗
+//  ██╔═══╝ ██╔══██║╚════██║╚════██║██║██║╚██╗██║██║   ██║
+//  ██║     ██║  ██║███████║███████║██║██║ ╚████║╚██████╔╝
+//  ╚═╝     ╚═╝  ╚═╝╚══════╝╚══════╝╚═╝╚═╝  ╚═══╝ ╚═════╝
+
+#[cfg(test)]
+mod passing {
+    use dataurl::{DataUrl, DataUrlParseError};
+
+    #[test]
+    fn must_reproduce_input_exactly_if_not_modified() -> Result<(), DataUrlParseError> {
+        let inputs: &[&str] = &[
+            "data:,Hello,%20World!",
+            "DATA:Text/HTML;Charset=windows-1252;foo=Bar;BASE64,PGI+aGk8L2I+",
+            "data:text/plain;charset=US-ASCII,a%2cb?q=1#Frag%20ment",
+            " data:;,a b ",
+            "data:image/png;base64,iVBORw0KGgo=#",
+        ];
+
+        for input in inputs {
+            let data_url: DataUrl = DataUrl::parse_lossless(input)?;
+
+            assert_eq!(data_url.to_string(), *input);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_parse_same_properties_as_regular_parse() -> Result<(), DataUrlParseError> {
+        let data_url: DataUrl = DataUrl::parse_lossless("data:Text/HTML;charset=utf8,%E2%98%80")?;
+
+        assert_eq!(data_url.media_type(), "text/html");
+        assert_eq!(data_url.charset(), "UTF-8");
+        assert_eq!(data_url.text(), "☀");
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_only_rewrite_media_type_if_it_was_changed() -> Result<(), DataUrlParseError> {
+        let mut data_url: DataUrl = DataUrl::parse_lossless("data:Text/Plain;foo=Bar,a%2cb#x")?;
+
+        data_url.set_media_type(Some("text/html".to_string()));
+
+        assert_eq!(data_url.to_string(), "data:text/html;foo=Bar,a%2cb#x");
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_only_rewrite_charset_if_it_was_changed() -> Result<(), DataUrlParseError> {
+        let mut data_url: DataUrl =
+            DataUrl::parse_lossless("data:text/plain;CHARSET=latin1;x=y,a")?;
+
+        data_url.set_charset(Some("utf8".to_string()));
+        assert_eq!(data_url.to_string(), "data:text/plain;charset=UTF-8;x=y,a");
+
+        data_url.set_charset(None);
+        assert_eq!(data_url.to_string(), "data:text/plain;x=y,a");
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_only_rewrite_payload_if_data_was_changed() -> Result<(), DataUrlParseError> {
+        let mut data_url: DataUrl = DataUrl::parse_lossless("data:Text/Plain;Base64,YQ==#Frag")?;
+
+        data_url.set_data(b"b");
+
+        assert_eq!(data_url.to_string(), "data:Text/Plain;Base64,Yg==#Frag");
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_reencode_payload_if_base64_was_toggled() -> Result<(), DataUrlParseError> {
+        let mut data_url: DataUrl = DataUrl::parse_lossless("data:Text/Plain;Base64;x=y,YQ==")?;
+
+        data_url.set_is_base64_encoded(false);
+
+        assert_eq!(data_url.to_string(), "data:Text/Plain;x=y,a");
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_only_rewrite_fragment_if_it_was_changed() -> Result<(), DataUrlParseError> {
+        let mut data_url: DataUrl = DataUrl::parse_lossless("data:,a%2Cb#one")?;
+
+        data_url.set_fragment(Some("two".to_string()));
+        assert_eq!(data_url.to_string(), "data:,a%2Cb#two");
+
+        data_url.set_fragment(None);
+        assert_eq!(data_url.to_string(), "data:,a%2Cb");
+
+        Ok(())
+    }
+}
+
+//  ███████╗ █████╗ ██╗██╗     ██╗███╗   ██╗ ██████╗
+//  ██╔════╝██╔══██╗██║██║     ██║████╗  ██║██╔════╝
+//  █████╗  ███████║██║██║     ██║██╔██╗ ██║██║  ███╗
+//  ██╔══╝  ██╔══██║██║██║     ██║██║╚██╗██║██║   ██║
+//  ██║     ██║  ██║██║███████╗██║██║ ╚████║╚██████╔╝
+//  ╚═╝     ╚═╝  ╚═╝╚═╝╚══════╝╚═╝╚═╝  ╚═══╝ ╚═════╝
+
+#[cfg(test)]
+mod failing {
+    use dataurl::{DataUrl, DataUrlParseError, ParseOptions};
+
+    #[test]
+    fn must_error_out_on_the_same_input_regular_parse_rejects() {
+        assert!(DataUrl::parse_lossless("").is_err());
+        assert!(DataUrl::parse_lossless("data:text/html").is_err());
+        assert!(DataUrl::parse_lossless("data:;base64,***").is_err());
+    }
+
+    #[test]
+    fn must_apply_parse_options() {
+        let options = ParseOptions {
+            max_decoded_len: Some(1),
+            ..ParseOptions::default()
+        };
+
+        assert!(DataUrl::parse_lossless_with_options("data:,a", &options).is_ok());
+        assert!(matches!(
+            DataUrl::parse_lossless_with_options("data:,ab", &options),
+            Err(DataUrlParseError::LimitExceeded)
+        ));
+    }
+}
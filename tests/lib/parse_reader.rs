@@ -0,0 +1,42 @@
+//  ██████╗  █████╗ ███████╗███████╗██╗███╗   ██╗ ██████╗
+//  ██╔══██╗██╔══██╗██╔════╝██╔════╝██║████╗  ██║██╔════╝
+//  ██████╔╝███████║███████╗███████╗██║██╔██╗ ██║██║  ███╗
+//  ██╔═══╝ ██╔══██║╚════██║╚════██║██║██║╚██╗██║██║   ██║
+//  ██║     ██║  ██║███████║███████║██║██║ ╚████║╚██████╔╝
+//  ╚═╝     ╚═╝  ╚═╝╚══════╝╚══════╝╚═╝╚═╝  ╚═══╝ ╚═════╝
+
+#[cfg(test)]
+mod passing {
+    use dataurl::DataUrl;
+    use std::io;
+
+    #[test]
+    fn must_parse_data_url_read_from_reader() -> io::Result<()> {
+        let data_url = DataUrl::parse_reader(&b"data:text/html,%3Cb%3E"[..])?;
+
+        assert_eq!(data_url.media_type(), "text/html");
+        assert_eq!(data_url.text(), "<b>");
+
+        Ok(())
+    }
+}
+
+//  ███████╗ █████╗ ██╗██╗     ██╗███╗   ██╗ ██████╗
+//  ██╔════╝██╔══██╗██║██║     ██║████╗  ██║██╔════╝
+//  █████╗  ███████║██║██║     ██║██╔██╗ ██║██║  ███╗
+//  ██╔══╝  ██╔══██║██║██║     ██║██║╚██╗██║██║   ██║
+//  ██║     ██║  ██║██║███████╗██║██║ ╚████║╚██████╔╝
+//  ╚═╝     ╚═╝  ╚═╝╚═╝╚══════╝╚═╝╚═╝  ╚═══╝ ╚═════╝
+
+#[cfg(test)]
+mod failing {
+    use dataurl::DataUrl;
+    use std::io;
+
+    #[test]
+    fn must_return_invalid_data_error_if_not_a_data_url() {
+        let result = DataUrl::parse_reader(&b"not a data URL"[..]);
+
+        assert_eq!(result.err().unwrap().kind(), io::ErrorKind::InvalidData);
+    }
+}
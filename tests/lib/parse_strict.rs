@@ -0,0 +1,153 @@
+//  ██████╗  █████╗ ███████╗███████╗██╗███╗   ██╗ ██████╗
+//  ██╔══██╗██╔══██╗██╔════╝██╔════╝██║████╗  ██║██╔════╝
+//  ██████╔╝███████║███████╗███████╗██║██╔██╗ ██║██║  ███╗
+//  ██╔═══╝ ██╔══██║╚════██║╚════██║██║██║╚██╗██║██║   ██║
+//  ██║     ██║  ██║███████║███████║██║██║ ╚████║╚██████╔╝
+//  ╚═╝     ╚═╝  ╚═╝╚══════╝╚══════╝╚═╝╚═╝  ╚═══╝ ╚═════╝
+
+#[cfg(test)]
+mod passing {
+    use dataurl::{DataUrl, DataUrlParseError, ParseMode, ParseOptions};
+
+    fn parse_strict(input: &str) -> Result<DataUrl, DataUrlParseError> {
+        DataUrl::parse_with_options(
+            input,
+            &ParseOptions {
+                mode: ParseMode::Rfc2397Strict,
+                ..ParseOptions::default()
+            },
+        )
+    }
+
+    #[test]
+    fn must_parse_examples_from_rfc() -> Result<(), DataUrlParseError> {
+        let data_url: DataUrl = parse_strict("data:,A%20brief%20note")?;
+        assert_eq!(data_url.text(), "A brief note");
+
+        let data_url: DataUrl = parse_strict(
+            "data:application/vnd-xxx-query,select_vcount,fcol_from_fieldtable/local",
+        )?;
+        assert_eq!(data_url.media_type(), "application/vnd-xxx-query");
+        assert_eq!(data_url.text(), "select_vcount,fcol_from_fieldtable/local");
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_parse_parameters_and_base64() -> Result<(), DataUrlParseError> {
+        let data_url: DataUrl =
+            parse_strict("data:text/plain;charset=utf-8;name=%22a%20b%22;base64,SGk/Pz8+#top")?;
+
+        assert_eq!(data_url.charset(), "UTF-8");
+        assert_eq!(data_url.parameter("name"), Some("\"a b\""));
+        assert!(data_url.is_base64_encoded());
+        assert_eq!(data_url.data(), b"Hi???>");
+        assert_eq!(data_url.fragment(), Some("top".to_string()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_accept_charset_without_media_type() -> Result<(), DataUrlParseError> {
+        let data_url: DataUrl = parse_strict("data:;charset=utf-8,%E2%9C%93")?;
+
+        assert_eq!(data_url.media_type(), "text/plain");
+        assert_eq!(data_url.text(), "✓");
+
+        Ok(())
+    }
+}
+
+//  ███████╗ █████╗ ██╗██╗     ██╗███╗   ██╗ ██████╗
+//  ██╔════╝██╔══██╗██║██║     ██║████╗  ██║██╔════╝
+//  █████╗  ███████║██║██║     ██║██╔██╗ ██║██║  ███╗
+//  ██╔══╝  ██╔══██║██║██║     ██║██║╚██╗██║██║   ██║
+//  ██║     ██║  ██║██║███████╗██║██║ ╚████║╚██████╔╝
+//  ╚═╝     ╚═╝  ╚═╝╚═╝╚══════╝╚═╝╚═╝  ╚═══╝ ╚═════╝
+
+#[cfg(test)]
+mod failing {
+    use dataurl::{DataUrl, DataUrlParseError, ParseMode, ParseOptions};
+
+    fn parse_strict(input: &str) -> Result<DataUrl, DataUrlParseError> {
+        DataUrl::parse_with_options(
+            input,
+            &ParseOptions {
+                mode: ParseMode::Rfc2397Strict,
+                ..ParseOptions::default()
+            },
+        )
+    }
+
+    #[test]
+    fn must_reject_invalid_media_type() {
+        assert!(matches!(
+            parse_strict("data:bad,a"),
+            Err(DataUrlParseError::InvalidMediaType)
+        ));
+        assert!(matches!(
+            parse_strict("data:text/plain/x,a"),
+            Err(DataUrlParseError::InvalidMediaType)
+        ));
+    }
+
+    #[test]
+    fn must_reject_empty_or_malformed_parameters() {
+        assert!(matches!(
+            parse_strict("data:;,"),
+            Err(DataUrlParseError::InvalidParameter)
+        ));
+        assert!(matches!(
+            parse_strict("data:text/plain;a=b=c,"),
+            Err(DataUrlParseError::InvalidParameter)
+        ));
+        assert!(matches!(
+            parse_strict("data:text/css;base64;charset=utf8,w5w="),
+            Err(DataUrlParseError::InvalidParameter)
+        ));
+    }
+
+    #[test]
+    fn must_reject_unknown_charset() {
+        assert!(matches!(
+            parse_strict("data:;charset=whatever,a"),
+            Err(DataUrlParseError::InvalidParameter)
+        ));
+    }
+
+    #[test]
+    fn must_reject_whitespace_and_unescaped_characters() {
+        assert!(matches!(
+            parse_strict(" data:,a"),
+            Err(DataUrlParseError::UrlParseError)
+        ));
+        assert!(matches!(
+            parse_strict("data:,a b"),
+            Err(DataUrlParseError::InvalidCharacter)
+        ));
+        assert!(matches!(
+            parse_strict("data:text/html,<p>"),
+            Err(DataUrlParseError::InvalidCharacter)
+        ));
+        assert!(matches!(
+            parse_strict("data:text/plain;charset=iso-8859-7,%be%fg"),
+            Err(DataUrlParseError::InvalidCharacter)
+        ));
+    }
+
+    #[test]
+    fn must_reject_illegal_base64() {
+        assert!(matches!(
+            parse_strict("data:;base64,SGVsbG8-"),
+            Err(DataUrlParseError::Base64DecodeError)
+        ));
+        assert!(matches!(
+            parse_strict("data:;base64,SGVsbG8"),
+            Err(DataUrlParseError::Base64DecodeError)
+        ));
+        assert!(matches!(
+            parse_strict("data:;base64,SG=sbG8="),
+            Err(DataUrlParseError::Base64DecodeError)
+        ));
+    }
+}
@@ -0,0 +1,128 @@
+//  ██████╗  █████╗ ███████╗███████╗██╗███╗   ██╗ ██████╗
+//  ██╔══██╗██╔══██╗██╔════╝██╔════╝██║████╗  ██║██╔════╝
+//  ██████╔╝███████║███████╗███████╗██║██╔██╗ ██║██║  ███╗
+//  ██╔═══╝ ██╔══██║╚════██║╚════██║██║██║╚██╗██║██║   ██║
+//  ██║     ██║  ██║███████║███████║██║██║ ╚████║╚██████╔╝
+//  ╚═╝     ╚═╝  ╚═╝╚══════╝╚══════╝╚═╝╚═╝  ╚═══╝ ╚═════╝
+
+#[cfg(test)]
+mod passing {
+    use dataurl::{DataUrl, DataUrlParseError, ParseMode, ParseOptions};
+
+    fn parse_whatwg(input: &str) -> Result<DataUrl, DataUrlParseError> {
+        DataUrl::parse_with_options(
+            input,
+            &ParseOptions {
+                mode: ParseMode::Whatwg,
+                ..ParseOptions::default()
+            },
+        )
+    }
+
+    #[test]
+    fn must_accept_what_to_string_outputs() -> Result<(), DataUrlParseError> {
+        let mut data_url = DataUrl::new();
+        data_url.set_is_base64_encoded(true);
+        data_url.set_data(&(0..=255).collect::<Vec<u8>>());
+
+        assert_eq!(parse_whatwg(&data_url.to_string())?.data(), data_url.data());
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_only_treat_trailing_base64_as_marker() -> Result<(), DataUrlParseError> {
+        let data_url: DataUrl = parse_whatwg("data:text/css;base64;charset=utf8,w5w=")?;
+
+        assert_eq!(data_url.media_type(), "text/css");
+        assert_eq!(data_url.charset(), "UTF-8");
+        assert!(!data_url.is_base64_encoded());
+        assert_eq!(data_url.data(), b"w5w=");
+
+        let data_url: DataUrl = parse_whatwg("data:text/plain;a=b; BASE64 ,SGk=")?;
+
+        assert!(data_url.is_base64_encoded());
+        assert_eq!(data_url.parameter("a"), Some("b"));
+        assert_eq!(data_url.data(), b"Hi");
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_decode_base64_forgivingly() -> Result<(), DataUrlParseError> {
+        let data_url: DataUrl = parse_whatwg("data:;base64,SGVs bG8")?;
+
+        assert_eq!(data_url.data(), b"Hello");
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_fall_back_to_text_plain_along_with_parameters() -> Result<(), DataUrlParseError> {
+        let data_url: DataUrl = parse_whatwg("data:text/plain/x;charset=utf-8;a=b,x")?;
+
+        assert_eq!(data_url.media_type_no_default(), None);
+        assert_eq!(data_url.charset(), "US-ASCII");
+        assert_eq!(data_url.parameters(), &[]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_parse_quoted_parameter_values() -> Result<(), DataUrlParseError> {
+        let data_url: DataUrl = parse_whatwg("data:text/plain;a=\"b;\\c\";d=e,x")?;
+
+        assert_eq!(data_url.parameter("a"), Some("b;c"));
+        assert_eq!(data_url.parameter("d"), Some("e"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_apply_text_plain_to_parameters_without_media_type() -> Result<(), DataUrlParseError> {
+        let data_url: DataUrl = parse_whatwg("data:;charset=utf-8,%E2%9C%93")?;
+
+        assert_eq!(data_url.media_type_no_default(), None);
+        assert_eq!(data_url.text(), "✓");
+
+        Ok(())
+    }
+}
+
+//  ███████╗ █████╗ ██╗██╗     ██╗███╗   ██╗ ██████╗
+//  ██╔════╝██╔══██╗██║██║     ██║████╗  ██║██╔════╝
+//  █████╗  ███████║██║██║     ██║██╔██╗ ██║██║  ███╗
+//  ██╔══╝  ██╔══██║██║██║     ██║██║╚██╗██║██║   ██║
+//  ██║     ██║  ██║██║███████╗██║██║ ╚████║╚██████╔╝
+//  ╚═╝     ╚═╝  ╚═╝╚═╝╚══════╝╚═╝╚═╝  ╚═══╝ ╚═════╝
+
+#[cfg(test)]
+mod failing {
+    use dataurl::{DataUrl, DataUrlParseError, ParseMode, ParseOptions};
+
+    fn parse_whatwg(input: &str) -> Result<DataUrl, DataUrlParseError> {
+        DataUrl::parse_with_options(
+            input,
+            &ParseOptions {
+                mode: ParseMode::Whatwg,
+                ..ParseOptions::default()
+            },
+        )
+    }
+
+    #[test]
+    fn must_reject_url_safe_base64() {
+        assert!(matches!(
+            parse_whatwg("data:;base64,SGVsbG8-"),
+            Err(DataUrlParseError::Base64DecodeError)
+        ));
+    }
+
+    #[test]
+    fn must_reject_base64_of_impossible_length() {
+        assert!(matches!(
+            parse_whatwg("data:;base64,SGVsb"),
+            Err(DataUrlParseError::Base64DecodeError)
+        ));
+    }
+}
@@ -0,0 +1,163 @@
+//  ██████╗  █████╗ ███████╗███████╗██╗███╗   ██╗ ██████╗
+//  ██╔══██╗██╔══██╗██╔════╝██╔════╝██║████╗  ██║██╔════╝
+//  ██████╔╝███████║███████╗███████╗██║██╔██╗ ██║██║  ███╗
+//  ██╔═══╝ ██╔══██║╚════██║╚════██║██║██║╚██╗██║██║   ██║
+//  ██║     ██║  ██║███████║███████║██║██║ ╚████║╚██████╔╝
+//  ╚═╝     ╚═╝  ╚═╝╚══════╝╚══════╝╚═╝╚═╝  ╚═══╝ ╚═════╝
+
+#[cfg(test)]
+mod passing {
+    use dataurl::{DataUrl, DataUrlParseError, ParseOptions};
+
+    #[test]
+    fn must_parse_without_limits_by_default() -> Result<(), DataUrlParseError> {
+        let data_url: DataUrl = DataUrl::parse_with_options(
+            "data:text/plain;a=1;b=2;charset=utf-8;base64,SGVsbG8=",
+            &ParseOptions::default(),
+        )?;
+
+        assert_eq!(data_url.parameter("a"), Some("1"));
+        assert_eq!(data_url.text(), "Hello");
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_parse_input_right_at_limits() -> Result<(), DataUrlParseError> {
+        let options = ParseOptions {
+            max_input_len: Some(26),
+            max_decoded_len: Some(5),
+            max_parameters: Some(1),
+            max_parameter_len: Some(3),
+            ..ParseOptions::default()
+        };
+        let data_url: DataUrl = DataUrl::parse_with_options("data:;a=1;base64,SGVsbG8=", &options)?;
+
+        assert_eq!(data_url.parameter("a"), Some("1"));
+        assert_eq!(data_url.text(), "Hello");
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_not_count_base64_marker_as_parameter() -> Result<(), DataUrlParseError> {
+        let options = ParseOptions {
+            max_parameters: Some(0),
+            ..ParseOptions::default()
+        };
+        let data_url: DataUrl =
+            DataUrl::parse_with_options("data:text/plain;base64,YQ==", &options)?;
+
+        assert_eq!(data_url.text(), "a");
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_ignore_whitespace_in_base64_payload_if_told_to() -> Result<(), DataUrlParseError> {
+        let options = ParseOptions {
+            ignore_base64_whitespace: true,
+            ..ParseOptions::default()
+        };
+        let data_url: DataUrl =
+            DataUrl::parse_with_options("data:;base64,SGVs bG8g%0Cd29y%0D%0AbGQ=", &options)?;
+
+        assert_eq!(data_url.text(), "Hello world");
+
+        Ok(())
+    }
+}
+
+//  ███████╗ █████╗ ██╗██╗     ██╗███╗   ██╗ ██████╗
+//  ██╔════╝██╔══██╗██║██║     ██║████╗  ██║██╔════╝
+//  █████╗  ███████║██║██║     ██║██╔██╗ ██║██║  ███╗
+//  ██╔══╝  ██╔══██║██║██║     ██║██║╚██╗██║██║   ██║
+//  ██║     ██║  ██║██║███████╗██║██║ ╚████║╚██████╔╝
+//  ╚═╝     ╚═╝  ╚═╝╚═╝╚══════╝╚═╝╚═╝  ╚═══╝ ╚═════╝
+
+#[cfg(test)]
+mod failing {
+    use dataurl::{DataUrl, DataUrlParseError, ParseOptions};
+
+    #[test]
+    fn must_reject_input_longer_than_max_input_len() {
+        let options = ParseOptions {
+            max_input_len: Some(7),
+            ..ParseOptions::default()
+        };
+
+        assert!(matches!(
+            DataUrl::parse_with_options("data:,ab", &options),
+            Err(DataUrlParseError::LimitExceeded)
+        ));
+    }
+
+    #[test]
+    fn must_reject_payload_longer_than_max_decoded_len() {
+        let options = ParseOptions {
+            max_decoded_len: Some(4),
+            ..ParseOptions::default()
+        };
+
+        assert!(matches!(
+            DataUrl::parse_with_options("data:;base64,SGVsbG8=", &options),
+            Err(DataUrlParseError::LimitExceeded)
+        ));
+        assert!(matches!(
+            DataUrl::parse_with_options("data:,Hello", &options),
+            Err(DataUrlParseError::LimitExceeded)
+        ));
+    }
+
+    #[test]
+    fn must_reject_too_many_parameters() {
+        let options = ParseOptions {
+            max_parameters: Some(2),
+            ..ParseOptions::default()
+        };
+
+        assert!(matches!(
+            DataUrl::parse_with_options("data:;a=1;b=2;c=3,", &options),
+            Err(DataUrlParseError::LimitExceeded)
+        ));
+    }
+
+    #[test]
+    fn must_reject_parameter_longer_than_max_parameter_len() {
+        let options = ParseOptions {
+            max_parameter_len: Some(8),
+            ..ParseOptions::default()
+        };
+
+        assert!(matches!(
+            DataUrl::parse_with_options("data:;charset=utf-8,", &options),
+            Err(DataUrlParseError::LimitExceeded)
+        ));
+    }
+
+    #[test]
+    fn must_reject_whitespace_in_base64_payload_by_default() {
+        assert!(matches!(
+            DataUrl::parse_with_options("data:;base64,SGVs bG8=", &ParseOptions::default()),
+            Err(DataUrlParseError::Base64DecodeError)
+        ));
+    }
+
+    #[test]
+    fn must_reject_oversized_payload_before_decoding_it() {
+        let options = ParseOptions {
+            max_decoded_len: Some(4),
+            ..ParseOptions::default()
+        };
+
+        // Too long to be worth decoding, let alone finding out that it isn't valid base64
+        assert!(matches!(
+            DataUrl::parse_with_options("data:;base64,SGVsbG8*", &options),
+            Err(DataUrlParseError::LimitExceeded)
+        ));
+        assert!(matches!(
+            DataUrl::parse_with_options("data:,%48%65%6C%6C%6F", &options),
+            Err(DataUrlParseError::LimitExceeded)
+        ));
+    }
+}
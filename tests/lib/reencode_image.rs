@@ -0,0 +1,106 @@
+//  ██████╗  █████╗ ███████╗███████╗██╗███╗   ██╗ ██████╗
+//  ██╔══██╗██╔══██╗██╔════╝██╔════╝██║████╗  ██║██╔════╝
+//  ██████╔╝███████║███████╗███████╗██║██╔██╗ ██║██║  ███╗
+//  ██╔═══╝ ██╔══██║╚════██║╚════██║██║██║╚██╗██║██║   ██║
+//  ██║     ██║  ██║███████║███████║██║██║ ╚████║╚██████╔╝
+//  ╚═╝     ╚═╝  ╚═╝╚══════╝╚══════╝╚═╝╚═╝  ╚═══╝ ╚═════╝
+
+#[cfg(test)]
+mod passing {
+    use dataurl::{DataUrl, ImageFormat, ImageOptions};
+    use std::io;
+
+    const LIZARD: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/_data_/lizard.png");
+
+    #[test]
+    fn must_scale_image_down_keeping_aspect_ratio() -> io::Result<()> {
+        let mut data_url = DataUrl::from_file(LIZARD)?;
+        let options = ImageOptions {
+            max_dimension: Some(64),
+            ..ImageOptions::default()
+        };
+        data_url.reencode_image(&options)?;
+
+        assert_eq!(data_url.media_type(), "image/png");
+        assert_eq!(data_url.image_dimensions(), Some((64, 31)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_convert_image_into_given_format() -> io::Result<()> {
+        let mut data_url = DataUrl::from_file(LIZARD)?;
+        let options = ImageOptions {
+            format: Some(ImageFormat::Jpeg),
+            quality: 50,
+            ..ImageOptions::default()
+        };
+        data_url.reencode_image(&options)?;
+
+        assert_eq!(data_url.media_type(), "image/jpeg");
+        assert!(data_url.is_base64_encoded());
+        assert!(data_url.data().starts_with(&[0xff, 0xd8, 0xff]));
+        assert_eq!(data_url.image_dimensions(), Some((199, 95)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_leave_small_enough_image_alone() -> io::Result<()> {
+        let mut data_url = DataUrl::from_file(LIZARD)?;
+        let original: Vec<u8> = data_url.data().to_vec();
+        let options = ImageOptions {
+            max_dimension: Some(200),
+            ..ImageOptions::default()
+        };
+        data_url.reencode_image(&options)?;
+
+        assert_eq!(data_url.data(), original);
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_turn_gif_into_png_when_scaling_it_down() -> io::Result<()> {
+        let mut data_url = DataUrl::parse(
+            "data:image/gif;base64,R0lGODlhAgACAIAAAP///wAAACwAAAAAAgACAAACAoRRADs=",
+        )
+        .unwrap();
+        let options = ImageOptions {
+            max_dimension: Some(1),
+            ..ImageOptions::default()
+        };
+        data_url.reencode_image(&options)?;
+
+        assert_eq!(data_url.media_type(), "image/png");
+        assert_eq!(data_url.image_dimensions(), Some((1, 1)));
+
+        Ok(())
+    }
+}
+
+//  ███████╗ █████╗ ██╗██╗     ██╗███╗   ██╗ ██████╗
+//  ██╔════╝██╔══██╗██║██║     ██║████╗  ██║██╔════╝
+//  █████╗  ███████║██║██║     ██║██╔██╗ ██║██║  ███╗
+//  ██╔══╝  ██╔══██║██║██║     ██║██║╚██╗██║██║   ██║
+//  ██║     ██║  ██║██║███████╗██║██║ ╚████║╚██████╔╝
+//  ╚═╝     ╚═╝  ╚═╝╚═╝╚══════╝╚═╝╚═╝  ╚═══╝ ╚═════╝
+
+#[cfg(test)]
+mod failing {
+    use dataurl::{DataUrl, ImageFormat, ImageOptions};
+    use std::io;
+
+    #[test]
+    fn must_refuse_to_reencode_non_images() {
+        let mut data_url = DataUrl::from_text("not an image");
+        let options = ImageOptions {
+            format: Some(ImageFormat::Png),
+            ..ImageOptions::default()
+        };
+        let err: io::Error = data_url.reencode_image(&options).unwrap_err();
+
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(data_url.data(), b"not an image");
+    }
+}
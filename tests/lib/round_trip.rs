@@ -0,0 +1,72 @@
+//  ██████╗  █████╗ ███████╗███████╗██╗███╗   ██╗ ██████╗
+//  ██╔══██╗██╔══██╗██╔════╝██╔════╝██║████╗  ██║██╔════╝
+//  ██████╔╝███████║███████╗███████╗██║██╔██╗ ██║██║  ███╗
+//  ██╔═══╝ ██╔══██║╚════██║╚════██║██║██║╚██╗██║██║   ██║
+//  ██║     ██║  ██║███████║███████║██║██║ ╚████║╚██████╔╝
+//  ╚═╝     ╚═╝  ╚═╝╚══════╝╚══════╝╚═╝╚═╝  ╚═══╝ ╚═════╝
+
+#[cfg(test)]
+mod passing {
+    use dataurl::DataUrl;
+    use proptest::prelude::*;
+
+    fn assert_same(a: &DataUrl, b: &DataUrl) {
+        assert_eq!(a.media_type_no_default(), b.media_type_no_default());
+        assert_eq!(a.charset_no_default(), b.charset_no_default());
+        assert_eq!(a.parameters(), b.parameters());
+        assert_eq!(a.is_base64_encoded(), b.is_base64_encoded());
+        assert_eq!(a.data(), b.data());
+        assert_eq!(a.fragment(), b.fragment());
+    }
+
+    fn charset() -> impl Strategy<Value = Option<String>> {
+        proptest::option::of(
+            prop_oneof![
+                Just("UTF-8"),
+                Just("UTF-16LE"),
+                Just("ISO-8859-2"),
+                Just("KOI8-R"),
+                Just("GBK"),
+                Just("Shift_JIS"),
+            ]
+            .prop_map(String::from),
+        )
+    }
+
+    proptest! {
+        #[test]
+        fn must_survive_to_string_and_parse(
+            media_type in proptest::option::of(prop_oneof!["[a-z]{1,8}/[a-z0-9+.-]{1,12}", ".*/.*"]),
+            charset in charset(),
+            parameters in proptest::collection::vec((prop_oneof!["x-[a-z0-9]{1,8}", ".*"], ".*"), 0..4),
+            is_base64_encoded in any::<bool>(),
+            data in proptest::collection::vec(any::<u8>(), 0..256),
+            fragment in proptest::option::of(".*"),
+        ) {
+            let mut data_url: DataUrl = DataUrl::new();
+            data_url.set_media_type(media_type);
+            data_url.set_charset(charset);
+            for (name, value) in parameters {
+                data_url.set_parameter(&name, Some(value));
+            }
+            data_url.set_is_base64_encoded(is_base64_encoded);
+            data_url.set_data(&data);
+            data_url.set_fragment(fragment);
+
+            let reparsed: DataUrl = DataUrl::parse(&data_url.to_string()).unwrap();
+
+            assert_same(&data_url, &reparsed);
+        }
+
+        #[test]
+        fn must_reach_fixed_point_after_parsing_anything(input in prop_oneof!["data:.*", "(?i:data):[a-z0-9/;=,%#?+ \\t-]{0,40}"]) {
+            if let Ok(data_url) = DataUrl::parse(&input) {
+                data_url.text();
+
+                let reparsed: DataUrl = DataUrl::parse(&data_url.to_string()).unwrap();
+
+                assert_same(&data_url, &reparsed);
+            }
+        }
+    }
+}
@@ -0,0 +1,72 @@
+//  ██████╗  █████╗ ███████╗███████╗██╗███╗   ██╗ ██████╗
+//  ██╔══██╗██╔══██╗██╔════╝██╔════╝██║████╗  ██║██╔════╝
+//  ██████╔╝███████║███████╗███████╗██║██╔██╗ ██║██║  ███╗
+//  ██╔═══╝ ██╔══██║╚════██║╚════██║██║██║╚██╗██║██║   ██║
+//  ██║     ██║  ██║███████║███████║██║██║ ╚████║╚██████╔╝
+//  ╚═╝     ╚═╝  ╚═╝╚══════╝╚══════╝╚═╝╚═╝  ╚═══╝ ╚═════╝
+
+#[cfg(test)]
+mod passing {
+    use dataurl::{DataUrl, DataUrlParseError};
+
+    #[test]
+    fn must_drop_default_media_type_and_charset() -> Result<(), DataUrlParseError> {
+        let data_url: DataUrl = DataUrl::parse("data:text/plain;charset=US-ASCII,a")?;
+
+        assert_eq!(data_url.to_canonical_string(), "data:,a");
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_produce_same_string_for_equivalent_data_urls() -> Result<(), DataUrlParseError> {
+        let a: DataUrl = DataUrl::parse("data:TEXT/HTML;charset=utf8;base64,PGI+")?;
+        let b: DataUrl = DataUrl::parse("data:text/html;charset=UTF-8,%3cb%3E")?;
+
+        assert_eq!(a.to_canonical_string(), b.to_canonical_string());
+        assert_eq!(
+            a.to_canonical_string(),
+            "data:text/html;charset=UTF-8,%3Cb%3E"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_sort_parameters_by_name() -> Result<(), DataUrlParseError> {
+        let a: DataUrl = DataUrl::parse("data:text/plain;a=1;B=2;c=3,x")?;
+        let b: DataUrl = DataUrl::parse("data:text/plain;c=3;B=2;a=1,x")?;
+
+        assert_eq!(a.to_canonical_string(), b.to_canonical_string());
+        assert_eq!(a.to_canonical_string(), "data:;a=1;b=2;c=3,x");
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_use_standard_base64_alphabet_for_binary_data() -> Result<(), DataUrlParseError> {
+        let data_url: DataUrl = DataUrl::parse("data:image/png,%FB%FF%FE")?;
+
+        assert_eq!(data_url.to_canonical_string(), "data:image/png;base64,+//+");
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_not_transcode_textual_data() -> Result<(), DataUrlParseError> {
+        let data_url: DataUrl = DataUrl::parse("data:;charset=gbk,%C4%E3")?;
+
+        assert_eq!(data_url.to_canonical_string(), "data:;charset=GBK,%C4%E3");
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_keep_fragment() -> Result<(), DataUrlParseError> {
+        let data_url: DataUrl = DataUrl::parse("data:,a#b")?;
+
+        assert_eq!(data_url.to_canonical_string(), "data:,a#b");
+
+        Ok(())
+    }
+}
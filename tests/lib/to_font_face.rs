@@ -0,0 +1,134 @@
+//  ██████╗  █████╗ ███████╗███████╗██╗███╗   ██╗ ██████╗
+//  ██╔══██╗██╔══██╗██╔════╝██╔════╝██║████╗  ██║██╔════╝
+//  ██████╔╝███████║███████╗███████╗██║██╔██╗ ██║██║  ███╗
+//  ██╔═══╝ ██╔══██║╚════██║╚════██║██║██║╚██╗██║██║   ██║
+//  ██║     ██║  ██║███████║███████║██║██║ ╚████║╚██████╔╝
+//  ╚═╝     ╚═╝  ╚═╝╚══════╝╚══════╝╚═╝╚═╝  ╚═══╝ ╚═════╝
+
+#[cfg(test)]
+mod passing {
+    use base64::{engine::general_purpose::STANDARD, Engine as _};
+    use dataurl::{DataUrl, DataUrlParseError, FontFaceOptions};
+
+    #[test]
+    fn must_output_font_face_rule_with_format_hint() -> Result<(), DataUrlParseError> {
+        let data_url: DataUrl = DataUrl::parse("data:font/woff2;base64,d09GMgABAAA=")?;
+        let options = FontFaceOptions {
+            family: "Inter".to_string(),
+            weight: Some("100 900".to_string()),
+            style: Some("italic".to_string()),
+            display: Some("swap".to_string()),
+        };
+
+        assert_eq!(
+            data_url.to_font_face(&options),
+            "@font-face {
+  font-family: \"Inter\";
+  src: url(\"data:font/woff2;base64,d09GMgABAAA=\") format(\"woff2\");
+  font-weight: 100 900;
+  font-style: italic;
+  font-display: swap;
+}"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_map_media_types_to_format_hints() -> Result<(), DataUrlParseError> {
+        for (media_type, format) in [
+            ("font/woff", "woff"),
+            ("font/ttf", "truetype"),
+            ("font/otf", "opentype"),
+        ] {
+            let data_url: DataUrl = DataUrl::parse(&format!("data:{};base64,AA==", media_type))?;
+
+            assert!(data_url
+                .to_font_face(&FontFaceOptions::default())
+                .contains(&format!(" format(\"{}\");", format)));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_escape_font_family() -> Result<(), DataUrlParseError> {
+        let data_url: DataUrl = DataUrl::parse("data:font/woff;base64,AA==")?;
+        let options = FontFaceOptions {
+            family: "A \"B\" \\C".to_string(),
+            ..FontFaceOptions::default()
+        };
+
+        assert!(data_url
+            .to_font_face(&options)
+            .contains("  font-family: \"A \\\"B\\\" \\\\C\";\n"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_use_standard_base64_even_if_parsed_url_safe_one() -> Result<(), DataUrlParseError> {
+        let data_url: DataUrl = DataUrl::parse_lossless("data:font/woff2;base64,d09GMg-_")?;
+        let css: String = data_url.to_font_face(&FontFaceOptions::default());
+        let payload: &str = css
+            .split("base64,")
+            .nth(1)
+            .unwrap()
+            .split('"')
+            .next()
+            .unwrap();
+
+        assert_eq!(
+            STANDARD.decode(payload).unwrap(),
+            [b'w', b'O', b'F', b'2', 0x0f, 0xbf]
+        );
+
+        Ok(())
+    }
+}
+
+//  ███████╗ █████╗ ██╗██╗     ██╗███╗   ██╗ ██████╗
+//  ██╔════╝██╔══██╗██║██║     ██║████╗  ██║██╔════╝
+//  █████╗  ███████║██║██║     ██║██╔██╗ ██║██║  ███╗
+//  ██╔══╝  ██╔══██║██║██║     ██║██║╚██╗██║██║   ██║
+//  ██║     ██║  ██║██║███████╗██║██║ ╚████║╚██████╔╝
+//  ╚═╝     ╚═╝  ╚═╝╚═╝╚══════╝╚═╝╚═╝  ╚═══╝ ╚═════╝
+
+#[cfg(test)]
+mod failing {
+    use dataurl::{DataUrl, DataUrlParseError, FontFaceOptions};
+
+    #[test]
+    fn must_not_add_format_hint_for_unknown_media_type() -> Result<(), DataUrlParseError> {
+        let data_url: DataUrl = DataUrl::parse("data:application/octet-stream;base64,AA==")?;
+
+        assert!(!data_url
+            .to_font_face(&FontFaceOptions::default())
+            .contains("format("));
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_leave_out_values_which_could_break_out_of_rule() -> Result<(), DataUrlParseError> {
+        let data_url: DataUrl = DataUrl::parse("data:font/woff2;base64,AA==")?;
+        let options = FontFaceOptions {
+            family: "A\"\nB".to_string(),
+            weight: Some("400;} body{color:red".to_string()),
+            style: Some("italic }".to_string()),
+            display: Some("swap\n".to_string()),
+        };
+
+        assert_eq!(options.invalid_descriptor(), Some("font-weight"));
+        assert_eq!(
+            data_url.to_font_face(&options),
+            "@font-face {
+  font-family: \"A\\\"\\a B\";
+  src: url(\"data:font/woff2;base64,AA==\") format(\"woff2\");
+  font-display: swap;
+}"
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,110 @@
+//  ██████╗  █████╗ ███████╗███████╗██╗███╗   ██╗ ██████╗
+//  ██╔══██╗██╔══██╗██╔════╝██╔════╝██║████╗  ██║██╔════╝
+//  ██████╔╝███████║███████╗███████╗██║██╔██╗ ██║██║  ███╗
+//  ██╔═══╝ ██╔══██║╚════██║╚════██║██║██║╚██╗██║██║   ██║
+//  ██║     ██║  ██║███████║███████║██║██║ ╚████║╚██████╔╝
+//  ╚═╝     ╚═╝  ╚═╝╚══════╝╚══════╝╚═╝╚═╝  ╚═══╝ ╚═════╝
+
+#[cfg(test)]
+mod passing {
+    use base64::{engine::general_purpose::STANDARD, Engine as _};
+    use dataurl::{DataUrl, DataUrlParseError, HtmlOptions};
+
+    #[test]
+    fn must_wrap_scripts_into_script_tag_with_integrity() -> Result<(), DataUrlParseError> {
+        let data_url: DataUrl = DataUrl::parse("data:text/javascript,1")?;
+        let options = HtmlOptions {
+            integrity: Some("sha256-abc".to_string()),
+            ..HtmlOptions::default()
+        };
+
+        assert_eq!(
+            data_url.to_html(&options),
+            "<script src=\"data:text/javascript,1\" integrity=\"sha256-abc\"></script>"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_wrap_stylesheets_into_link_tag() -> Result<(), DataUrlParseError> {
+        let data_url: DataUrl = DataUrl::parse("data:text/css,a")?;
+
+        assert_eq!(
+            data_url.to_html(&HtmlOptions::default()),
+            "<link rel=\"stylesheet\" href=\"data:text/css,a\">"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_wrap_images_into_img_tag() -> Result<(), DataUrlParseError> {
+        let data_url: DataUrl = DataUrl::parse("data:image/gif;base64,R0lGODlh")?;
+
+        assert_eq!(
+            data_url.to_html(&HtmlOptions::default()),
+            "<img src=\"data:image/gif;base64,R0lGODlh\">"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_add_dimensions_to_img_tag() -> Result<(), DataUrlParseError> {
+        let data_url: DataUrl = DataUrl::parse(
+            "data:image/gif;base64,R0lGODlhAQABAIAAAAAAAP///yH5BAEAAAAALAAAAAABAAEAAAIBRAA7",
+        )?;
+
+        assert_eq!(
+            data_url.to_html(&HtmlOptions::default()),
+            "<img src=\"data:image/gif;base64,R0lGODlhAQABAIAAAAAAAP///yH5BAEAAAAALAAAAAABAAEAAAIBRAA7\" width=\"1\" height=\"1\">"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_add_srcset_and_alt_to_img_tag() -> Result<(), DataUrlParseError> {
+        let data_url: DataUrl = DataUrl::parse("data:image/svg+xml,<svg width='8' height='4'/>")?;
+        let options = HtmlOptions {
+            alt: Some("A \"box\"".to_string()),
+            srcset: vec![("data:image/png;base64,AA==".to_string(), "2x".to_string())],
+            ..HtmlOptions::default()
+        };
+
+        assert_eq!(
+            data_url.to_html(&options),
+            "<img src=\"data:image/svg+xml,%3Csvg%20width%3D%278%27%20height%3D%274%27%2F%3E\" srcset=\"data:image/png;base64,AA== 2x\" width=\"8\" height=\"4\" alt=\"A &quot;box&quot;\">"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_escape_quotes_in_attributes() -> Result<(), DataUrlParseError> {
+        let data_url: DataUrl = DataUrl::parse_lossless("data:,\"")?;
+
+        assert_eq!(
+            data_url.to_html(&HtmlOptions::default()),
+            "<object data=\"data:,&quot;\" type=\"text/plain\"></object>"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_use_standard_base64_even_if_parsed_url_safe_one() -> Result<(), DataUrlParseError> {
+        let data_url: DataUrl = DataUrl::parse_lossless("data:image/png;base64,-_-_")?;
+        let html: String = data_url.to_html(&HtmlOptions::default());
+        let src: &str = html.split('"').nth(1).unwrap();
+
+        assert_eq!(src, "data:image/png;base64,+/+/");
+        assert_eq!(
+            STANDARD.decode(src.split_once(',').unwrap().1).unwrap(),
+            [0xfb, 0xff, 0xbf]
+        );
+
+        Ok(())
+    }
+}
@@ -85,6 +85,22 @@ mod passing {
         Ok(())
     }
 
+    #[test]
+    fn must_use_standard_base64_alphabet() -> Result<(), DataUrlParseError> {
+        let mut data_url = DataUrl::new();
+
+        data_url.set_media_type(Some("application/octet-stream".to_string()));
+        data_url.set_is_base64_encoded(true);
+        data_url.set_data(&[0xfb, 0xff, 0xbf]);
+
+        assert_eq!(
+            data_url.to_string(),
+            "data:application/octet-stream;base64,+/+/"
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn must_properly_encode_utf8_emoji() -> Result<(), DataUrlParseError> {
         let mut data_url = DataUrl::new();
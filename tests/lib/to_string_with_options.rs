@@ -0,0 +1,65 @@
+//  ██████╗  █████╗ ███████╗███████╗██╗███╗   ██╗ ██████╗
+//  ██╔══██╗██╔══██╗██╔════╝██╔════╝██║████╗  ██║██╔════╝
+//  ██████╔╝███████║███████╗███████╗██║██╔██╗ ██║██║  ███╗
+//  ██╔═══╝ ██╔══██║╚════██║╚════██║██║██║╚██╗██║██║   ██║
+//  ██║     ██║  ██║███████║███████║██║██║ ╚████║╚██████╔╝
+//  ╚═╝     ╚═╝  ╚═╝╚══════╝╚══════╝╚═╝╚═╝  ╚═══╝ ╚═════╝
+
+#[cfg(test)]
+mod passing {
+    use dataurl::{DataUrl, DataUrlParseError, StringOptions};
+
+    #[test]
+    fn must_output_same_as_to_string_by_default() -> Result<(), DataUrlParseError> {
+        let data_url: DataUrl = DataUrl::parse("data:text/plain;base64,SGVsbG8sIHdvcmxkIQ==")?;
+
+        assert_eq!(
+            data_url.to_string_with_options(&StringOptions::default()),
+            data_url.to_string()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_wrap_base64_payload() -> Result<(), DataUrlParseError> {
+        let data_url: DataUrl =
+            DataUrl::parse("data:text/plain;base64,SGVsbG8sIHdvcmxkISBIZWxsbywgd29ybGQh#top")?;
+        let wrapped: String = data_url.to_string_with_options(&StringOptions { wrap: Some(32) });
+
+        assert_eq!(
+            wrapped,
+            "data:text/plain;base64,SGVsbG8sI\r\nHdvcmxkISBIZWxsbywgd29ybGQh#top"
+        );
+        assert_eq!(
+            DataUrl::parse(&wrapped)?.text(),
+            "Hello, world! Hello, world!"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_start_payload_on_new_line_if_meta_data_is_too_long() -> Result<(), DataUrlParseError> {
+        let data_url: DataUrl = DataUrl::parse("data:text/plain;base64,SGVsbG8=")?;
+
+        assert_eq!(
+            data_url.to_string_with_options(&StringOptions { wrap: Some(4) }),
+            "data:text/plain;base64,\r\nSGVs\r\nbG8="
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn must_not_wrap_percent_encoded_payload() -> Result<(), DataUrlParseError> {
+        let data_url: DataUrl = DataUrl::parse("data:,Hello%2C%20world%21")?;
+
+        assert_eq!(
+            data_url.to_string_with_options(&StringOptions { wrap: Some(8) }),
+            "data:,Hello%2C%20world%21"
+        );
+
+        Ok(())
+    }
+}
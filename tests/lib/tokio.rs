@@ -0,0 +1,148 @@
+//  ██████╗  █████╗ ███████╗███████╗██╗███╗   ██╗ ██████╗
+//  ██╔══██╗██╔══██╗██╔════╝██╔════╝██║████╗  ██║██╔════╝
+//  ██████╔╝███████║███████╗███████╗██║██╔██╗ ██║██║  ███╗
+//  ██╔═══╝ ██╔══██║╚════██║╚════██║██║██║╚██╗██║██║   ██║
+//  ██║     ██║  ██║███████║███████║██║██║ ╚████║╚██████╔╝
+//  ╚═╝     ╚═╝  ╚═╝╚══════╝╚══════╝╚═╝╚═╝  ╚═══╝ ╚═════╝
+
+#[cfg(test)]
+mod passing {
+    use dataurl::{DataUrl, ParseOptions};
+    use std::io;
+    use tokio::io::AsyncReadExt;
+
+    #[tokio::test]
+    async fn must_read_payload_and_sniff_media_type() -> io::Result<()> {
+        let data_url = dataurl::tokio::from_reader(&b"GIF89a"[..]).await?;
+
+        assert_eq!(data_url.to_string(), "data:image/gif;base64,R0lGODlh");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn must_parse_data_url_read_from_reader() -> io::Result<()> {
+        let data_url = dataurl::tokio::parse_reader(&b"data:text/html,%3Cb%3E"[..]).await?;
+
+        assert_eq!(data_url.media_type(), "text/html");
+        assert_eq!(data_url.text(), "<b>");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn must_write_data_url_and_its_data() -> io::Result<()> {
+        let data_url = DataUrl::parse("data:;base64,SGk=").unwrap();
+        let mut url: Vec<u8> = Vec::new();
+        let mut data: Vec<u8> = Vec::new();
+
+        dataurl::tokio::write_to(&data_url, &mut url).await?;
+        dataurl::tokio::write_data_to(&data_url, &mut data).await?;
+
+        assert_eq!(url, b"data:;base64,SGk=");
+        assert_eq!(data, b"Hi");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn must_parse_the_same_however_input_is_split() -> io::Result<()> {
+        let input: &[u8] = b" data:text/plain,Hello%2C\t%20W%6frld\xff? #frag%20 \n";
+        let expected: DataUrl = DataUrl::parse(&String::from_utf8_lossy(input)).unwrap();
+
+        for i in 0..=input.len() {
+            let data_url = dataurl::tokio::parse_reader((&input[..i]).chain(&input[i..])).await?;
+
+            assert_eq!(data_url.to_string(), expected.to_string());
+            assert_eq!(data_url.data(), expected.data());
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn must_decode_payload_spanning_many_chunks() -> io::Result<()> {
+        let data: Vec<u8> = (0..100_000).map(|i| (i % 251) as u8).collect();
+        let input: String = DataUrl::from_bytes(&data).to_string();
+        let options: ParseOptions = ParseOptions {
+            max_decoded_len: Some(data.len()),
+            ..ParseOptions::default()
+        };
+
+        let data_url =
+            dataurl::tokio::parse_reader_with_options(input.as_bytes(), &options).await?;
+
+        assert_eq!(data_url.data(), &data[..]);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn must_write_data_url_spanning_many_chunks() -> io::Result<()> {
+        let data: Vec<u8> = (0..100_000).map(|i| (i % 251) as u8).collect();
+        let mut data_url: DataUrl = DataUrl::from_bytes(&data);
+        data_url.set_fragment(Some("end".to_string()));
+        let mut url: Vec<u8> = Vec::new();
+
+        dataurl::tokio::write_to(&data_url, &mut url).await?;
+
+        assert_eq!(url, data_url.to_string().as_bytes());
+
+        Ok(())
+    }
+}
+
+//  ███████╗ █████╗ ██╗██╗     ██╗███╗   ██╗ ██████╗
+//  ██╔════╝██╔══██╗██║██║     ██║████╗  ██║██╔════╝
+//  █████╗  ███████║██║██║     ██║██╔██╗ ██║██║  ███╗
+//  ██╔══╝  ██╔══██║██║██║     ██║██║╚██╗██║██║   ██║
+//  ██║     ██║  ██║██║███████╗██║██║ ╚████║╚██████╔╝
+//  ╚═╝     ╚═╝  ╚═╝╚═╝╚══════╝╚═╝╚═╝  ╚═══╝ ╚═════╝
+
+#[cfg(test)]
+mod failing {
+    use dataurl::ParseOptions;
+    use std::io;
+
+    #[tokio::test]
+    async fn must_return_invalid_data_error_if_not_a_data_url() {
+        let result = dataurl::tokio::parse_reader(&b"not a data URL"[..]).await;
+
+        assert_eq!(result.err().unwrap().kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[tokio::test]
+    async fn must_stop_reading_once_over_input_limit() {
+        let options: ParseOptions = ParseOptions {
+            max_input_len: Some(16),
+            ..ParseOptions::default()
+        };
+        let result =
+            dataurl::tokio::parse_reader_with_options(&b"data:,Hello%2C%20World!"[..], &options)
+                .await;
+
+        assert_eq!(result.err().unwrap().kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[tokio::test]
+    async fn must_stop_reading_once_over_decoded_limit() {
+        let options: ParseOptions = ParseOptions {
+            max_decoded_len: Some(4),
+            ..ParseOptions::default()
+        };
+        let result = dataurl::tokio::parse_reader_with_options(
+            &b"data:;base64,SGVsbG8sIFdvcmxkIQ=="[..],
+            &options,
+        )
+        .await;
+
+        assert_eq!(result.err().unwrap().kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[tokio::test]
+    async fn must_not_read_more_data_than_limit_allows() {
+        let result = dataurl::tokio::from_reader_with_limit(&b"GIF89a"[..], 4).await;
+
+        assert_eq!(result.err().unwrap().kind(), io::ErrorKind::InvalidData);
+    }
+}
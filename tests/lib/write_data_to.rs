@@ -0,0 +1,23 @@
+//  ██████╗  █████╗ ███████╗███████╗██╗███╗   ██╗ ██████╗
+//  ██╔══██╗██╔══██╗██╔════╝██╔════╝██║████╗  ██║██╔════╝
+//  ██████╔╝███████║███████╗███████╗██║██╔██╗ ██║██║  ███╗
+//  ██╔═══╝ ██╔══██║╚════██║╚════██║██║██║╚██╗██║██║   ██║
+//  ██║     ██║  ██║███████║███████║██║██║ ╚████║╚██████╔╝
+//  ╚═╝     ╚═╝  ╚═╝╚══════╝╚══════╝╚═╝╚═╝  ╚═══╝ ╚═════╝
+
+#[cfg(test)]
+mod passing {
+    use dataurl::{DataUrl, DataUrlParseError};
+
+    #[test]
+    fn must_write_decoded_payload() -> Result<(), DataUrlParseError> {
+        let data_url: DataUrl = DataUrl::parse("data:;base64,SGVsbG8=")?;
+        let mut output: Vec<u8> = Vec::new();
+
+        data_url.write_data_to(&mut output).unwrap();
+
+        assert_eq!(output, b"Hello");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,24 @@
+//  ██████╗  █████╗ ███████╗███████╗██╗███╗   ██╗ ██████╗
+//  ██╔══██╗██╔══██╗██╔════╝██╔════╝██║████╗  ██║██╔════╝
+//  ██████╔╝███████║███████╗███████╗██║██╔██╗ ██║██║  ███╗
+//  ██╔═══╝ ██╔══██║╚════██║╚════██║██║██║╚██╗██║██║   ██║
+//  ██║     ██║  ██║███████║███████║██║██║ ╚████║╚██████╔╝
+//  ╚═╝     ╚═╝  ╚═╝╚══════╝╚══════╝╚═╝╚═╝  ╚═══╝ ╚═════╝
+
+#[cfg(test)]
+mod passing {
+    use dataurl::DataUrl;
+    use std::io;
+
+    #[test]
+    fn must_write_serialized_data_url() -> io::Result<()> {
+        let data_url = DataUrl::from_text("Hello, World!");
+        let mut output: Vec<u8> = Vec::new();
+
+        data_url.write_to(&mut output)?;
+
+        assert_eq!(output, b"data:,Hello%2C%20World%21");
+
+        Ok(())
+    }
+}
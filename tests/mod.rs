@@ -1,2 +1,5 @@
+#![allow(special_module_name)]
+
+#[cfg(feature = "cli")]
 mod cli;
 mod lib;
@@ -0,0 +1,110 @@
+# Run with: maturin develop && pytest (requires a virtualenv with maturin and pytest)
+
+import pytest
+
+import dataurl
+
+#  ██████╗  █████╗ ███████╗███████╗██╗███╗   ██╗ ██████╗
+#  ██╔══██╗██╔══██╗██╔════╝██╔════╝██║████╗  ██║██╔════╝
+#  ██████╔╝███████║███████╗███████╗██║██╔██╗ ██║██║  ███╗
+#  ██╔═══╝ ██╔══██║╚════██║╚════██║██║██║╚██╗██║██║   ██║
+#  ██║     ██║  ██║███████║███████║██║██║ ╚████║╚██████╔╝
+#  ╚═╝     ╚═╝  ╚═╝╚══════╝╚══════╝╚═╝╚═╝  ╚═══╝ ╚═════╝
+
+
+def test_must_parse_data_url():
+    data_url = dataurl.parse("data:text/html;charset=utf-8;base64,PGI+PC9iPg==#f")
+
+    assert data_url.media_type == "text/html"
+    assert data_url.charset == "UTF-8"
+    assert data_url.base64
+    assert data_url.data == b"<b></b>"
+    assert data_url.fragment == "f"
+    assert not data_url.is_binary
+    assert data_url.text() == "<b></b>"
+
+
+def test_must_parse_via_static_method():
+    data_url = dataurl.DataUrl.parse("data:,Hello,%20World!")
+
+    assert data_url.media_type == "text/plain"
+    assert data_url.charset == "US-ASCII"
+    assert not data_url.base64
+    assert data_url.data == b"Hello, World!"
+    assert data_url.fragment is None
+
+
+def test_must_build_data_url_using_properties():
+    data_url = dataurl.DataUrl()
+    data_url.media_type = "application/json"
+    data_url.charset = "utf8"
+    data_url.base64 = True
+    data_url.data = b"{}"
+    data_url.fragment = "top"
+
+    assert str(data_url) == "data:application/json;charset=UTF-8;base64,e30=#top"
+    assert repr(data_url) == "DataUrl(\"data:application/json;charset=UTF-8;base64,e30=#top\")"
+
+
+def test_must_unset_properties_when_given_none():
+    data_url = dataurl.parse("data:text/css;charset=utf-8,a#b")
+    data_url.media_type = None
+    data_url.charset = None
+    data_url.fragment = None
+
+    assert str(data_url) == "data:,a"
+
+
+def test_must_return_data_as_bytes():
+    data_url = dataurl.parse("data:;base64,AAEC")
+
+    assert isinstance(data_url.data, bytes)
+    assert data_url.data == b"\x00\x01\x02"
+
+
+#  ███████╗ █████╗ ██╗██╗     ██╗███╗   ██╗ ██████╗
+#  ██╔════╝██╔══██╗██║██║     ██║████╗  ██║██╔════╝
+#  █████╗  ███████║██║██║     ██║██╔██╗ ██║██║  ███╗
+#  ██╔══╝  ██╔══██║██║██║     ██║██║╚██╗██║██║   ██║
+#  ██║     ██║  ██║██║███████╗██║██║ ╚████║╚██████╔╝
+#  ╚═╝     ╚═╝  ╚═╝╚═╝╚══════╝╚═╝╚═╝  ╚═══╝ ╚═════╝
+
+
+def test_must_raise_url_parse_error():
+    with pytest.raises(dataurl.UrlParseError):
+        dataurl.parse("https://example.com")
+
+
+def test_must_raise_malformed_data_url_error():
+    with pytest.raises(dataurl.MalformedDataUrlError):
+        dataurl.parse("data:text/plain")
+
+
+def test_must_raise_base64_decode_error():
+    with pytest.raises(dataurl.Base64DecodeError):
+        dataurl.parse("data:;base64,***")
+
+
+def test_must_make_parse_errors_catchable_as_value_error():
+    assert issubclass(dataurl.DataUrlParseError, ValueError)
+
+    for error in (dataurl.UrlParseError, dataurl.MalformedDataUrlError, dataurl.Base64DecodeError):
+        assert issubclass(error, dataurl.DataUrlParseError)
+
+
+def test_must_reject_invalid_media_type():
+    data_url = dataurl.DataUrl()
+
+    with pytest.raises(ValueError):
+        data_url.media_type = "bad media type"
+
+    assert data_url.media_type == "text/plain"
+
+
+def test_must_reject_invalid_charset():
+    data_url = dataurl.DataUrl()
+
+    with pytest.raises(ValueError):
+        data_url.charset = "not-a-charset"
+
+    assert data_url.charset == "US-ASCII"
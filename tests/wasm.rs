@@ -0,0 +1,110 @@
+// Run with: make test-wasm (requires wasm-bindgen-test-runner and Node.js)
+#![cfg(all(target_arch = "wasm32", feature = "wasm"))]
+
+//  ██████╗  █████╗ ███████╗███████╗██╗███╗   ██╗ ██████╗
+//  ██╔══██╗██╔══██╗██╔════╝██╔════╝██║████╗  ██║██╔════╝
+//  ██████╔╝███████║███████╗███████╗██║██╔██╗ ██║██║  ███╗
+//  ██╔═══╝ ██╔══██║╚════██║╚════██║██║██║╚██╗██║██║   ██║
+//  ██║     ██║  ██║███████║███████║██║██║ ╚████║╚██████╔╝
+//  ╚═╝     ╚═╝  ╚═╝╚══════╝╚══════╝╚═╝╚═╝  ╚═══╝ ╚═════╝
+
+#[cfg(test)]
+mod passing {
+    use dataurl::wasm::{encode, inspect, parse, to_string, JsDataUrl};
+    use js_sys::{Object, Reflect, Uint8Array};
+    use wasm_bindgen::JsValue;
+    use wasm_bindgen_test::*;
+
+    fn get(object: &JsValue, key: &str) -> JsValue {
+        Reflect::get(object, &key.into()).unwrap()
+    }
+
+    #[wasm_bindgen_test]
+    fn must_parse_data_url_and_expose_payload_as_uint8array() {
+        let data_url: JsDataUrl = parse("data:text/html;charset=utf8,%3Cb%3E").unwrap();
+
+        assert_eq!(data_url.media_type(), "text/html");
+        assert_eq!(data_url.charset(), "UTF-8");
+        assert_eq!(data_url.data().to_vec(), b"<b>".to_vec());
+        assert_eq!(data_url.text(), "<b>");
+    }
+
+    #[wasm_bindgen_test]
+    fn must_encode_bytes_using_options_object() {
+        let options: Object = Object::new();
+        Reflect::set(&options, &"mediaType".into(), &"image/gif".into()).unwrap();
+        Reflect::set(&options, &"base64".into(), &true.into()).unwrap();
+        let data: Vec<u8> = Uint8Array::from(&b"GIF89a"[..]).to_vec();
+
+        assert_eq!(
+            encode(&data, options.into()).unwrap(),
+            "data:image/gif;base64,R0lGODlh"
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn must_encode_bytes_without_options() {
+        assert_eq!(encode(b"a", JsValue::UNDEFINED).unwrap(), "data:,a");
+    }
+
+    #[wasm_bindgen_test]
+    fn must_inspect_data_url_into_plain_object() {
+        let info: JsValue = inspect("data:image/png;base64,iVBORw==#x").unwrap().into();
+
+        assert_eq!(get(&info, "mediaType"), "image/png");
+        assert_eq!(get(&info, "base64"), true);
+        assert_eq!(get(&info, "binary"), true);
+        assert_eq!(get(&info, "size"), 4);
+        assert_eq!(get(&info, "fragment"), "x");
+    }
+
+    #[wasm_bindgen_test]
+    fn must_serialize_modified_data_url() {
+        let mut data_url: JsDataUrl = JsDataUrl::new();
+
+        data_url.set_data(b"Hello");
+        data_url.set_base64(true);
+
+        assert_eq!(to_string(&data_url), "data:;base64,SGVsbG8=");
+    }
+}
+
+//  ███████╗ █████╗ ██╗██╗     ██╗███╗   ██╗ ██████╗
+//  ██╔════╝██╔══██╗██║██║     ██║████╗  ██║██╔════╝
+//  █████╗  ███████║██║██║     ██║██╔██╗ ██║██║  ███╗
+//  ██╔══╝  ██╔══██║██║██║     ██║██║╚██╗██║██║   ██║
+//  ██║     ██║  ██║██║███████╗██║██║ ╚████║╚██████╔╝
+//  ╚═╝     ╚═╝  ╚═╝╚═╝╚══════╝╚═╝╚═╝  ╚═══╝ ╚═════╝
+
+#[cfg(test)]
+mod failing {
+    use dataurl::wasm::{encode, parse};
+    use js_sys::{Error, Object, Reflect};
+    use wasm_bindgen::{JsCast, JsValue};
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn must_throw_error_object_with_code_on_parse_failure() {
+        let err: JsValue = parse("data:;base64,***").err().unwrap();
+
+        assert!(err.is_instance_of::<Error>());
+        assert_eq!(err.dyn_ref::<Error>().unwrap().name(), "DataUrlParseError");
+        assert_eq!(
+            Reflect::get(&err, &"code".into()).unwrap(),
+            "Base64DecodeError"
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn must_throw_error_object_with_code_on_invalid_option() {
+        let options: Object = Object::new();
+        Reflect::set(&options, &"charset".into(), &"BAD-CHARSET".into()).unwrap();
+        let err: JsValue = encode(b"a", options.into()).err().unwrap();
+
+        assert_eq!(err.dyn_ref::<Error>().unwrap().name(), "DataUrlBuildError");
+        assert_eq!(
+            Reflect::get(&err, &"code".into()).unwrap(),
+            "InvalidCharset"
+        );
+    }
+}